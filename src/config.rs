@@ -1,8 +1,11 @@
 use std::env;
 
+use crate::constants::FETCH_DEPTH_LIMIT;
+
 pub struct Config {
     pub(crate) port: String,
     pub(crate) wh_path: String,
+    pub(crate) fetch_depth: usize,
 }
 
 impl Config {
@@ -15,6 +18,14 @@ impl Config {
             Ok(v) => v.to_string(),
             Err(_) => "warehouse".to_string(),
         };
-        Self { port, wh_path }
+        let fetch_depth = match env::var("FETCH_DEPTH") {
+            Ok(v) => v.parse::<usize>().unwrap_or(FETCH_DEPTH_LIMIT as usize),
+            Err(_) => FETCH_DEPTH_LIMIT as usize,
+        };
+        Self {
+            port,
+            wh_path,
+            fetch_depth,
+        }
     }
 }
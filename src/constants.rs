@@ -3,6 +3,10 @@ pub const NUMBER: &str = "n";
 pub const BOOL: &str = "b";
 pub const NULL: &str = "null";
 pub const KEEP: &str = "keep";
+pub const VERSION: &str = "version";
+pub const EXCLUDE: &str = "exclude";
+pub const LINK_ID: &str = "link_id";
+pub const STEP_RESULT: &str = "ref";
 pub const COLLECTION_NAME: &str = "collection";
 pub const PATH_TO_VALUE: &str = "value";
 pub const DELETED: &str = "deleted";
@@ -18,11 +22,24 @@ pub const INSERT_QUERY: &str = "insert";
 pub const FIND_QUERY: &str = "find";
 pub const GET_QUERY: &str = "get";
 pub const UPDATE_QUERY: &str = "update";
+pub const UPSERT_QUERY: &str = "upsert";
+pub const REPLACE_QUERY: &str = "replace";
+pub const IF_VERSION_QUERY: &str = "if_version";
+pub const ASSERT_QUERY: &str = "assert";
 pub const DELETE_QUERY: &str = "delete";
 pub const SORT_QUERY: &str = "sort";
 pub const LIMIT_QUERY: &str = "limit";
 pub const OFFSET_QUERY: &str = "offset";
 pub const PROJECT_QUERY: &str = "project";
+pub const REFERENCED_BY_QUERY: &str = "referenced_by";
+pub const ON_DELETE_QUERY: &str = "on_delete";
+pub const GROUP_QUERY: &str = "group";
+pub const COUNT_QUERY: &str = "count";
+pub const DISTINCT_QUERY: &str = "distinct";
+pub const CURSOR_QUERY: &str = "cursor";
+pub const DEPTH_QUERY: &str = "depth";
+pub const TRAVERSE_QUERY: &str = "traverse";
+pub const SHORTEST_PATH_QUERY: &str = "shortest_path";
 
 // FIND OPERATORS
 pub const EQ_OPERATOR: &str = "eq";
@@ -31,6 +48,17 @@ pub const GT_OPERATOR: &str = "gt";
 pub const GTE_OPERATOR: &str = "gte";
 pub const LT_OPERATOR: &str = "lt";
 pub const LTE_OPERATOR: &str = "lte";
+pub const IN_OPERATOR: &str = "in";
+pub const NIN_OPERATOR: &str = "nin";
+pub const EXISTS_OPERATOR: &str = "exists";
+pub const TYPE_OPERATOR: &str = "type";
+pub const STARTS_WITH_OPERATOR: &str = "starts_with";
+pub const CONTAINS_OPERATOR: &str = "contains";
+pub const IEQ_OPERATOR: &str = "ieq";
+pub const REGEX_OPERATOR: &str = "regex";
+pub const ANY_OPERATOR: &str = "any";
+pub const SIZE_OPERATOR: &str = "size";
+pub const VIA_OPERATOR: &str = "via";
 
 pub const AND_OPERATOR: &str = "and";
 pub const OR_OPERATOR: &str = "or";
@@ -44,10 +72,31 @@ pub const DESC_OPERATOR: &str = "desc";
 // SET OPERATORS
 pub const SET_OPERATOR: &str = "set";
 pub const INC_OPERATOR: &str = "inc";
+pub const MUL_OPERATOR: &str = "mul";
+pub const MIN_OPERATOR: &str = "min";
+pub const MAX_OPERATOR: &str = "max";
+pub const UNSET_OPERATOR: &str = "unset";
+pub const RENAME_OPERATOR: &str = "rename";
+pub const NOW_OPERATOR: &str = "now";
+pub const PUSH_OPERATOR: &str = "push";
+pub const PULL_OPERATOR: &str = "pull";
+pub const ADD_TO_SET_OPERATOR: &str = "add_to_set";
+pub const POP_OPERATOR: &str = "pop";
+pub const INSERT_AT_OPERATOR: &str = "insert_at";
+
+// PROJECT EXPRESSIONS
+pub const CONCAT_EXPRESSION: &str = "concat";
+pub const ADD_EXPRESSION: &str = "add";
+pub const SUB_EXPRESSION: &str = "sub";
+pub const MUL_EXPRESSION: &str = "mul";
+pub const DIV_EXPRESSION: &str = "div";
+pub const COND_EXPRESSION: &str = "cond";
+pub const FETCH_EXPRESSION: &str = "fetch";
 
 // RESPONSE
 pub const RESPONSE_OBJECTS: &str = "objects";
 pub const RESPONSE_IDS: &str = "ids";
+pub const RESPONSE_DATA: &str = "data";
 pub const QUERY_RESPONSE: &str = "response";
 pub const TRANSACTION_RESPONSE: &str = "result";
 pub const INSERT_META: &str = "insert_meta";
@@ -56,8 +105,84 @@ pub const FIND_META: &str = "find_meta";
 pub const UPDATE_META: &str = "update_meta";
 pub const DELETE_META: &str = "delete_meta";
 
+// GROUP ACCUMULATORS
+pub const COUNT_ACCUMULATOR: &str = "count";
+pub const SUM_ACCUMULATOR: &str = "sum";
+pub const AVG_ACCUMULATOR: &str = "avg";
+pub const MIN_ACCUMULATOR: &str = "min";
+pub const MAX_ACCUMULATOR: &str = "max";
+pub const PUSH_ACCUMULATOR: &str = "push";
+pub const FIRST_ACCUMULATOR: &str = "first";
+pub const LAST_ACCUMULATOR: &str = "last";
+
+// DELETE POLICIES
+pub const RESTRICT_POLICY: &str = "restrict";
+pub const CASCADE_POLICY: &str = "cascade";
+pub const SET_NULL_POLICY: &str = "set_null";
+
+// CONFLICT POLICIES
+pub const ERROR_ON_CONFLICT: &str = "error";
+pub const SKIP_ON_CONFLICT: &str = "skip";
+
+// OPTIONS
+pub const DEPTH_OPTION: &str = "depth";
+pub const ON_CONFLICT_OPTION: &str = "on_conflict";
+pub const CREATE_PATHS_OPTION: &str = "create_paths";
+pub const MIN_OPTION: &str = "min";
+pub const MAX_OPTION: &str = "max";
+pub const TO_OPTION: &str = "to";
+
 // OTHER
 pub const ROOT: &str = "root";
 pub const INTERNAL_COLLECTION_NAME: &str = "_internal";
+pub const POLICIES_COLLECTION_NAME: &str = "_policies";
+pub const PROCEDURES_COLLECTION_NAME: &str = "_procedures";
+pub const IDEMPOTENCY_KEY: &str = "idempotency_key";
+pub const SAVEPOINT: &str = "savepoint";
+pub const ROLLBACK_TO: &str = "rollback_to";
+pub const PREPARE: &str = "prepare";
+pub const EXECUTE: &str = "execute";
+pub const TEMPLATE: &str = "template";
+pub const PARAM: &str = "param";
+pub const PROCEDURE: &str = "procedure";
+pub const CALL: &str = "call";
+pub const MORE: &str = "more";
+pub const CLOSE: &str = "close";
+
+/// Prefixes of the primitives. Links to the collections with these names
+/// can not be parsed, so new collections can not take them
+pub const RESERVED_COLLECTION_NAMES: [&str; 18] = [
+    STRING,
+    NUMBER,
+    BOOL,
+    NULL,
+    KEEP,
+    VERSION,
+    EXCLUDE,
+    LINK_ID,
+    STEP_RESULT,
+    COLLECTION_NAME,
+    PATH_TO_VALUE,
+    DELETED,
+    UTS,
+    DELETE_QUERY,
+    REFERENCED_BY_QUERY,
+    COUNT_ACCUMULATOR,
+    PARAM,
+    ROOT,
+];
+
+pub const IDEMPOTENCY_KEYS_LIMIT: usize = 10000;
+pub const PREPARED_QUERIES_LIMIT: usize = 10000;
+pub const CURSORS_LIMIT: usize = 10000;
+pub const CURSOR_IDLE_TIMEOUT_SECS: u64 = 600;
 
 pub const FETCH_DEPTH_LIMIT: i32 = 1024;
+
+// PATHS
+pub const PATH_SEPARATOR: &str = ".";
+pub const WILDCARD_PATH: &str = "*";
+pub const LAST_INDEX_PATH: &str = "$last";
+
+pub const REGEX_PATTERN_LENGTH_LIMIT: usize = 1024;
+pub const REGEX_SIZE_LIMIT: usize = 1024 * 1024;
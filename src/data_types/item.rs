@@ -3,6 +3,7 @@ use crate::data_types::modifier::ModifierItem;
 use crate::data_types::primitives::link::Link;
 use crate::data_types::primitives::Primitive;
 use crate::data_types::vector::VectorItem;
+use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::map::TySONMap;
 use crate::tyson::modifier::TySONModifier;
 use crate::tyson::vector::TySONVector;
@@ -26,6 +27,15 @@ impl Item {
         }
     }
 
+    pub fn get_prefix(&self) -> String {
+        match self {
+            Self::Primitive(o) => o.get_prefix(),
+            Self::Map(o) => o.get_prefix(),
+            Self::Vector(o) => o.get_prefix(),
+            Self::Modifier(o) => o.get_prefix(),
+        }
+    }
+
     pub(crate) fn to_link(&self) -> Result<Link, DBError> {
         match self {
             Self::Primitive(Primitive::Link(o)) => Ok(o.clone()),
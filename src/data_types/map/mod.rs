@@ -1,25 +1,59 @@
 use crate::constants::{
-    EQ_OPERATOR, GTE_OPERATOR, GT_OPERATOR, INC_OPERATOR, LTE_OPERATOR, LT_OPERATOR, NEQ_OPERATOR,
-    PROJECT_QUERY, RESPONSE_OBJECTS, SET_OPERATOR, STORAGE_MAP,
+    ADD_TO_SET_OPERATOR, ANY_OPERATOR, CONTAINS_OPERATOR, EQ_OPERATOR, EXISTS_OPERATOR,
+    GROUP_QUERY, GTE_OPERATOR, GT_OPERATOR, IEQ_OPERATOR, INC_OPERATOR, INSERT_AT_OPERATOR,
+    INSERT_QUERY, IN_OPERATOR, LTE_OPERATOR, LT_OPERATOR, MAX_OPERATOR, MIN_OPERATOR, MUL_OPERATOR,
+    NEQ_OPERATOR, NIN_OPERATOR, NOW_OPERATOR, ON_DELETE_QUERY, POP_OPERATOR, PROJECT_QUERY,
+    PULL_OPERATOR, PUSH_OPERATOR, REGEX_OPERATOR, RENAME_OPERATOR, RESPONSE_OBJECTS, SET_OPERATOR,
+    SHORTEST_PATH_QUERY, SIZE_OPERATOR, STARTS_WITH_OPERATOR, STORAGE_MAP, TEMPLATE,
+    TRAVERSE_QUERY, TYPE_OPERATOR, UNSET_OPERATOR, VIA_OPERATOR,
 };
 use crate::data_types::item::Item;
 use crate::data_types::map::storage::StorageMap;
+use crate::data_types::map::template::QueryTemplate;
 use crate::data_types::primitives::Primitive;
+use crate::query::find::operators::any::AnyOperator;
+use crate::query::find::operators::contains::ContainsOperator;
 use crate::query::find::operators::eq::EqOperator;
+use crate::query::find::operators::exists::ExistsOperator;
 use crate::query::find::operators::gt::GtOperator;
 use crate::query::find::operators::gte::GteOperator;
+use crate::query::find::operators::ieq::IeqOperator;
 use crate::query::find::operators::lt::LtOperator;
 use crate::query::find::operators::lte::LteOperator;
 use crate::query::find::operators::neq::NeqOperator;
+use crate::query::find::operators::nin::NinOperator;
+use crate::query::find::operators::r#in::InOperator;
+use crate::query::find::operators::r#type::TypeOperator;
+use crate::query::find::operators::regex::RegexOperator;
+use crate::query::find::operators::size::SizeOperator;
+use crate::query::find::operators::starts_with::StartsWithOperator;
+use crate::query::find::operators::via::ViaOperator;
+use crate::query::group::query::GroupQuery;
+use crate::query::insert::query::InsertWithIdsQuery;
+use crate::query::on_delete::query::OnDeleteQuery;
 use crate::query::project::query::ProjectQuery;
+use crate::query::shortest_path::query::ShortestPathQuery;
+use crate::query::traverse::query::TraverseQuery;
+use crate::query::update::operators::add_to_set::AddToSetOperator;
 use crate::query::update::operators::inc::IncOperator;
+use crate::query::update::operators::insert_at::InsertAtOperator;
+use crate::query::update::operators::max::MaxOperator;
+use crate::query::update::operators::min::MinOperator;
+use crate::query::update::operators::mul::MulOperator;
+use crate::query::update::operators::now::NowOperator;
+use crate::query::update::operators::pop::PopOperator;
+use crate::query::update::operators::pull::PullOperator;
+use crate::query::update::operators::push::PushOperator;
+use crate::query::update::operators::rename::RenameOperator;
 use crate::query::update::operators::set::SetOperator;
+use crate::query::update::operators::unset::UnsetOperator;
 use crate::response::objects::ResponseObjects;
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::map::TySONMap;
 use crate::DBError;
 
 pub mod storage;
+pub mod template;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum MapItem {
@@ -27,6 +61,11 @@ pub enum MapItem {
 
     // QUERIES
     ProjectQuery(ProjectQuery),
+    OnDeleteQuery(OnDeleteQuery),
+    GroupQuery(GroupQuery),
+    TraverseQuery(TraverseQuery),
+    ShortestPathQuery(ShortestPathQuery),
+    InsertWithIdsQuery(InsertWithIdsQuery),
 
     // FIND OPERATORS
     EqOperator(EqOperator),
@@ -35,13 +74,36 @@ pub enum MapItem {
     GteOperator(GteOperator),
     LtOperator(LtOperator),
     LteOperator(LteOperator),
+    InOperator(InOperator),
+    NinOperator(NinOperator),
+    ExistsOperator(ExistsOperator),
+    TypeOperator(TypeOperator),
+    StartsWithOperator(StartsWithOperator),
+    ContainsOperator(ContainsOperator),
+    IeqOperator(IeqOperator),
+    RegexOperator(RegexOperator),
+    AnyOperator(AnyOperator),
+    SizeOperator(SizeOperator),
+    ViaOperator(ViaOperator),
 
     // UPDATE OPERATORS
     SetOperator(SetOperator),
     IncOperator(IncOperator),
+    MulOperator(MulOperator),
+    MinOperator(MinOperator),
+    MaxOperator(MaxOperator),
+    UnsetOperator(UnsetOperator),
+    RenameOperator(RenameOperator),
+    NowOperator(NowOperator),
+    PushOperator(PushOperator),
+    PullOperator(PullOperator),
+    AddToSetOperator(AddToSetOperator),
+    PopOperator(PopOperator),
+    InsertAtOperator(InsertAtOperator),
 
     // RESPONSE
     ResponseObjects(ResponseObjects),
+    QueryTemplate(QueryTemplate),
 }
 
 impl BaseTySONItemInterface for MapItem {
@@ -49,6 +111,11 @@ impl BaseTySONItemInterface for MapItem {
         match self {
             MapItem::StorageMap(o) => o.get_prefix(),
             MapItem::ProjectQuery(o) => o.get_prefix(),
+            MapItem::OnDeleteQuery(o) => o.get_prefix(),
+            MapItem::GroupQuery(o) => o.get_prefix(),
+            MapItem::TraverseQuery(o) => o.get_prefix(),
+            MapItem::ShortestPathQuery(o) => o.get_prefix(),
+            MapItem::InsertWithIdsQuery(o) => o.get_prefix(),
             MapItem::SetOperator(o) => o.get_prefix(),
             MapItem::EqOperator(o) => o.get_prefix(),
             MapItem::NeqOperator(o) => o.get_prefix(),
@@ -56,8 +123,31 @@ impl BaseTySONItemInterface for MapItem {
             MapItem::GteOperator(o) => o.get_prefix(),
             MapItem::LtOperator(o) => o.get_prefix(),
             MapItem::LteOperator(o) => o.get_prefix(),
+            MapItem::InOperator(o) => o.get_prefix(),
+            MapItem::NinOperator(o) => o.get_prefix(),
+            MapItem::ExistsOperator(o) => o.get_prefix(),
+            MapItem::TypeOperator(o) => o.get_prefix(),
+            MapItem::StartsWithOperator(o) => o.get_prefix(),
+            MapItem::ContainsOperator(o) => o.get_prefix(),
+            MapItem::IeqOperator(o) => o.get_prefix(),
+            MapItem::RegexOperator(o) => o.get_prefix(),
+            MapItem::AnyOperator(o) => o.get_prefix(),
+            MapItem::SizeOperator(o) => o.get_prefix(),
+            MapItem::ViaOperator(o) => o.get_prefix(),
             MapItem::IncOperator(o) => o.get_prefix(),
+            MapItem::MulOperator(o) => o.get_prefix(),
+            MapItem::MinOperator(o) => o.get_prefix(),
+            MapItem::MaxOperator(o) => o.get_prefix(),
+            MapItem::UnsetOperator(o) => o.get_prefix(),
+            MapItem::RenameOperator(o) => o.get_prefix(),
+            MapItem::NowOperator(o) => o.get_prefix(),
+            MapItem::PushOperator(o) => o.get_prefix(),
+            MapItem::PullOperator(o) => o.get_prefix(),
+            MapItem::AddToSetOperator(o) => o.get_prefix(),
+            MapItem::PopOperator(o) => o.get_prefix(),
+            MapItem::InsertAtOperator(o) => o.get_prefix(),
             MapItem::ResponseObjects(o) => o.get_prefix(),
+            MapItem::QueryTemplate(o) => o.get_prefix(),
         }
     }
 }
@@ -70,6 +160,15 @@ impl TySONMap for MapItem {
         match prefix.as_str() {
             STORAGE_MAP => Ok(MapItem::StorageMap(StorageMap::new("".to_string())?)),
             PROJECT_QUERY => Ok(MapItem::ProjectQuery(ProjectQuery::new("".to_string())?)),
+            ON_DELETE_QUERY => Ok(MapItem::OnDeleteQuery(OnDeleteQuery::new("".to_string())?)),
+            GROUP_QUERY => Ok(MapItem::GroupQuery(GroupQuery::new("".to_string())?)),
+            TRAVERSE_QUERY => Ok(MapItem::TraverseQuery(TraverseQuery::new("".to_string())?)),
+            SHORTEST_PATH_QUERY => Ok(MapItem::ShortestPathQuery(ShortestPathQuery::new(
+                "".to_string(),
+            )?)),
+            INSERT_QUERY => Ok(MapItem::InsertWithIdsQuery(InsertWithIdsQuery::new(
+                "".to_string(),
+            )?)),
             SET_OPERATOR => Ok(MapItem::SetOperator(SetOperator::new("".to_string())?)),
             EQ_OPERATOR => Ok(MapItem::EqOperator(EqOperator::new("".to_string())?)),
             NEQ_OPERATOR => Ok(MapItem::NeqOperator(NeqOperator::new("".to_string())?)),
@@ -77,10 +176,45 @@ impl TySONMap for MapItem {
             GTE_OPERATOR => Ok(MapItem::GteOperator(GteOperator::new("".to_string())?)),
             LT_OPERATOR => Ok(MapItem::LtOperator(LtOperator::new("".to_string())?)),
             LTE_OPERATOR => Ok(MapItem::LteOperator(LteOperator::new("".to_string())?)),
+            IN_OPERATOR => Ok(MapItem::InOperator(InOperator::new("".to_string())?)),
+            NIN_OPERATOR => Ok(MapItem::NinOperator(NinOperator::new("".to_string())?)),
+            EXISTS_OPERATOR => Ok(MapItem::ExistsOperator(ExistsOperator::new(
+                "".to_string(),
+            )?)),
+            TYPE_OPERATOR => Ok(MapItem::TypeOperator(TypeOperator::new("".to_string())?)),
+            STARTS_WITH_OPERATOR => Ok(MapItem::StartsWithOperator(StartsWithOperator::new(
+                "".to_string(),
+            )?)),
+            CONTAINS_OPERATOR => Ok(MapItem::ContainsOperator(ContainsOperator::new(
+                "".to_string(),
+            )?)),
+            IEQ_OPERATOR => Ok(MapItem::IeqOperator(IeqOperator::new("".to_string())?)),
+            REGEX_OPERATOR => Ok(MapItem::RegexOperator(RegexOperator::new("".to_string())?)),
+            ANY_OPERATOR => Ok(MapItem::AnyOperator(AnyOperator::new("".to_string())?)),
+            SIZE_OPERATOR => Ok(MapItem::SizeOperator(SizeOperator::new("".to_string())?)),
+            VIA_OPERATOR => Ok(MapItem::ViaOperator(ViaOperator::new("".to_string())?)),
             INC_OPERATOR => Ok(MapItem::IncOperator(IncOperator::new("".to_string())?)),
+            MUL_OPERATOR => Ok(MapItem::MulOperator(MulOperator::new("".to_string())?)),
+            MIN_OPERATOR => Ok(MapItem::MinOperator(MinOperator::new("".to_string())?)),
+            MAX_OPERATOR => Ok(MapItem::MaxOperator(MaxOperator::new("".to_string())?)),
+            UNSET_OPERATOR => Ok(MapItem::UnsetOperator(UnsetOperator::new("".to_string())?)),
+            RENAME_OPERATOR => Ok(MapItem::RenameOperator(RenameOperator::new(
+                "".to_string(),
+            )?)),
+            NOW_OPERATOR => Ok(MapItem::NowOperator(NowOperator::new("".to_string())?)),
+            PUSH_OPERATOR => Ok(MapItem::PushOperator(PushOperator::new("".to_string())?)),
+            PULL_OPERATOR => Ok(MapItem::PullOperator(PullOperator::new("".to_string())?)),
+            ADD_TO_SET_OPERATOR => Ok(MapItem::AddToSetOperator(AddToSetOperator::new(
+                "".to_string(),
+            )?)),
+            POP_OPERATOR => Ok(MapItem::PopOperator(PopOperator::new("".to_string())?)),
+            INSERT_AT_OPERATOR => Ok(MapItem::InsertAtOperator(InsertAtOperator::new(
+                "".to_string(),
+            )?)),
             RESPONSE_OBJECTS => Ok(MapItem::ResponseObjects(ResponseObjects::new(
                 "".to_string(),
             )?)),
+            TEMPLATE => Ok(MapItem::QueryTemplate(QueryTemplate::new("".to_string())?)),
             _ => Err(DBError::new("Unexpected map type")),
         }
     }
@@ -89,6 +223,11 @@ impl TySONMap for MapItem {
         match self {
             MapItem::StorageMap(o) => o.insert(k, v),
             MapItem::ProjectQuery(o) => o.insert(k, v),
+            MapItem::OnDeleteQuery(o) => o.insert(k, v),
+            MapItem::GroupQuery(o) => o.insert(k, v),
+            MapItem::TraverseQuery(o) => o.insert(k, v),
+            MapItem::ShortestPathQuery(o) => o.insert(k, v),
+            MapItem::InsertWithIdsQuery(o) => o.insert(k, v),
             MapItem::SetOperator(o) => o.insert(k, v),
             MapItem::EqOperator(o) => o.insert(k, v),
             MapItem::NeqOperator(o) => o.insert(k, v),
@@ -96,8 +235,31 @@ impl TySONMap for MapItem {
             MapItem::GteOperator(o) => o.insert(k, v),
             MapItem::LtOperator(o) => o.insert(k, v),
             MapItem::LteOperator(o) => o.insert(k, v),
+            MapItem::InOperator(o) => o.insert(k, v),
+            MapItem::NinOperator(o) => o.insert(k, v),
+            MapItem::ExistsOperator(o) => o.insert(k, v),
+            MapItem::TypeOperator(o) => o.insert(k, v),
+            MapItem::StartsWithOperator(o) => o.insert(k, v),
+            MapItem::ContainsOperator(o) => o.insert(k, v),
+            MapItem::IeqOperator(o) => o.insert(k, v),
+            MapItem::RegexOperator(o) => o.insert(k, v),
+            MapItem::AnyOperator(o) => o.insert(k, v),
+            MapItem::SizeOperator(o) => o.insert(k, v),
+            MapItem::ViaOperator(o) => o.insert(k, v),
             MapItem::IncOperator(o) => o.insert(k, v),
+            MapItem::MulOperator(o) => o.insert(k, v),
+            MapItem::MinOperator(o) => o.insert(k, v),
+            MapItem::MaxOperator(o) => o.insert(k, v),
+            MapItem::UnsetOperator(o) => o.insert(k, v),
+            MapItem::RenameOperator(o) => o.insert(k, v),
+            MapItem::NowOperator(o) => o.insert(k, v),
+            MapItem::PushOperator(o) => o.insert(k, v),
+            MapItem::PullOperator(o) => o.insert(k, v),
+            MapItem::AddToSetOperator(o) => o.insert(k, v),
+            MapItem::PopOperator(o) => o.insert(k, v),
+            MapItem::InsertAtOperator(o) => o.insert(k, v),
             MapItem::ResponseObjects(o) => o.insert(k, v),
+            MapItem::QueryTemplate(o) => o.insert(k, v),
         }
     }
 
@@ -105,6 +267,11 @@ impl TySONMap for MapItem {
         match self {
             MapItem::StorageMap(o) => o.get_items(),
             MapItem::ProjectQuery(o) => o.get_items(),
+            MapItem::OnDeleteQuery(o) => o.get_items(),
+            MapItem::GroupQuery(o) => o.get_items(),
+            MapItem::TraverseQuery(o) => o.get_items(),
+            MapItem::ShortestPathQuery(o) => o.get_items(),
+            MapItem::InsertWithIdsQuery(o) => o.get_items(),
             MapItem::SetOperator(o) => o.get_items(),
             MapItem::EqOperator(o) => o.get_items(),
             MapItem::NeqOperator(o) => o.get_items(),
@@ -112,8 +279,44 @@ impl TySONMap for MapItem {
             MapItem::GteOperator(o) => o.get_items(),
             MapItem::LtOperator(o) => o.get_items(),
             MapItem::LteOperator(o) => o.get_items(),
+            MapItem::InOperator(o) => o.get_items(),
+            MapItem::NinOperator(o) => o.get_items(),
+            MapItem::ExistsOperator(o) => o.get_items(),
+            MapItem::TypeOperator(o) => o.get_items(),
+            MapItem::StartsWithOperator(o) => o.get_items(),
+            MapItem::ContainsOperator(o) => o.get_items(),
+            MapItem::IeqOperator(o) => o.get_items(),
+            MapItem::RegexOperator(o) => o.get_items(),
+            MapItem::AnyOperator(o) => o.get_items(),
+            MapItem::SizeOperator(o) => o.get_items(),
+            MapItem::ViaOperator(o) => o.get_items(),
             MapItem::IncOperator(o) => o.get_items(),
+            MapItem::MulOperator(o) => o.get_items(),
+            MapItem::MinOperator(o) => o.get_items(),
+            MapItem::MaxOperator(o) => o.get_items(),
+            MapItem::UnsetOperator(o) => o.get_items(),
+            MapItem::RenameOperator(o) => o.get_items(),
+            MapItem::NowOperator(o) => o.get_items(),
+            MapItem::PushOperator(o) => o.get_items(),
+            MapItem::PullOperator(o) => o.get_items(),
+            MapItem::AddToSetOperator(o) => o.get_items(),
+            MapItem::PopOperator(o) => o.get_items(),
+            MapItem::InsertAtOperator(o) => o.get_items(),
             MapItem::ResponseObjects(o) => o.get_items(),
+            MapItem::QueryTemplate(o) => o.get_items(),
+        }
+    }
+
+    fn serialize(&self) -> String {
+        match self {
+            MapItem::QueryTemplate(o) => o.serialize(),
+            _ => {
+                let mut contents: Vec<String> = vec![];
+                for (k, v) in self.get_items() {
+                    contents.push(format!("{}:{}", k.serialize(), v.serialize()));
+                }
+                format!("{}{{{}}}", self.get_prefix(), contents.join(","))
+            }
         }
     }
 
@@ -58,4 +58,8 @@ impl StorageMap {
         self.insert(Primitive::new(STRING.to_string(), k)?, item)?;
         Ok(true)
     }
+
+    pub(crate) fn remove_by_str(&mut self, k: &str) -> Option<Item> {
+        self.values.remove(&StringPrimitive::from(k))
+    }
 }
@@ -0,0 +1,44 @@
+use crate::constants::TEMPLATE;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+/// Steps of a prepared query in the transaction format.
+/// The keys are transaction items, not primitives, so the source is kept as is
+/// and parsed by the transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct QueryTemplate {
+    pub(crate) source: String,
+}
+
+impl BaseTySONItemInterface for QueryTemplate {
+    fn get_prefix(&self) -> String {
+        TEMPLATE.to_string()
+    }
+}
+
+impl TySONMap for QueryTemplate {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            source: "".to_string(),
+        })
+    }
+
+    fn insert(&mut self, _: Primitive, _: Item) -> Result<bool, DBError> {
+        Err(DBError::new("Template items can not be inserted"))
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        vec![]
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::QueryTemplate(self))
+    }
+
+    fn serialize(&self) -> String {
+        format!("{}{{{}}}", self.get_prefix(), self.source)
+    }
+}
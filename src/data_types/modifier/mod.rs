@@ -1,7 +1,26 @@
-use crate::constants::{ASC_OPERATOR, DESC_OPERATOR, LIMIT_QUERY, NOT_OPERATOR, OFFSET_QUERY};
+use crate::constants::{
+    ASC_OPERATOR, ASSERT_QUERY, AVG_ACCUMULATOR, COUNT_QUERY, CURSOR_QUERY, DEPTH_QUERY,
+    DESC_OPERATOR, DISTINCT_QUERY, FIRST_ACCUMULATOR, IF_VERSION_QUERY, LAST_ACCUMULATOR,
+    LIMIT_QUERY, MAX_ACCUMULATOR, MIN_ACCUMULATOR, NOT_OPERATOR, OFFSET_QUERY, PUSH_ACCUMULATOR,
+    REPLACE_QUERY, SUM_ACCUMULATOR,
+};
+use crate::query::assert::operators::count::CountAssertion;
+use crate::query::assert::query::AssertQuery;
+use crate::query::cursor::query::CursorQuery;
+use crate::query::depth::query::DepthQuery;
+use crate::query::distinct::query::DistinctQuery;
 use crate::query::find::operators::not::NotOperator;
+use crate::query::group::operators::avg::AvgAccumulator;
+use crate::query::group::operators::first::FirstAccumulator;
+use crate::query::group::operators::last::LastAccumulator;
+use crate::query::group::operators::max::MaxAccumulator;
+use crate::query::group::operators::min::MinAccumulator;
+use crate::query::group::operators::push::PushAccumulator;
+use crate::query::group::operators::sum::SumAccumulator;
+use crate::query::if_version::query::IfVersionQuery;
 use crate::query::limit::query::LimitQuery;
 use crate::query::offset::query::OffsetQuery;
+use crate::query::replace::query::ReplaceQuery;
 use crate::query::sort::query::{AscOperator, DescOperator};
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::modifier::TySONModifier;
@@ -14,6 +33,20 @@ pub enum ModifierItem {
     DescOperator(DescOperator),
     LimitQuery(LimitQuery),
     OffsetQuery(OffsetQuery),
+    CursorQuery(CursorQuery),
+    DepthQuery(DepthQuery),
+    DistinctQuery(DistinctQuery),
+    ReplaceQuery(ReplaceQuery),
+    IfVersionQuery(IfVersionQuery),
+    AssertQuery(AssertQuery),
+    CountAssertion(CountAssertion),
+    SumAccumulator(SumAccumulator),
+    AvgAccumulator(AvgAccumulator),
+    MinAccumulator(MinAccumulator),
+    MaxAccumulator(MaxAccumulator),
+    PushAccumulator(PushAccumulator),
+    FirstAccumulator(FirstAccumulator),
+    LastAccumulator(LastAccumulator),
 }
 
 impl BaseTySONItemInterface for ModifierItem {
@@ -24,6 +57,20 @@ impl BaseTySONItemInterface for ModifierItem {
             ModifierItem::DescOperator(o) => o.get_prefix(),
             ModifierItem::LimitQuery(o) => o.get_prefix(),
             ModifierItem::OffsetQuery(o) => o.get_prefix(),
+            ModifierItem::CursorQuery(o) => o.get_prefix(),
+            ModifierItem::DepthQuery(o) => o.get_prefix(),
+            ModifierItem::DistinctQuery(o) => o.get_prefix(),
+            ModifierItem::ReplaceQuery(o) => o.get_prefix(),
+            ModifierItem::IfVersionQuery(o) => o.get_prefix(),
+            ModifierItem::AssertQuery(o) => o.get_prefix(),
+            ModifierItem::CountAssertion(o) => o.get_prefix(),
+            ModifierItem::SumAccumulator(o) => o.get_prefix(),
+            ModifierItem::AvgAccumulator(o) => o.get_prefix(),
+            ModifierItem::MinAccumulator(o) => o.get_prefix(),
+            ModifierItem::MaxAccumulator(o) => o.get_prefix(),
+            ModifierItem::PushAccumulator(o) => o.get_prefix(),
+            ModifierItem::FirstAccumulator(o) => o.get_prefix(),
+            ModifierItem::LastAccumulator(o) => o.get_prefix(),
         }
     }
 }
@@ -36,6 +83,22 @@ impl TySONModifier for ModifierItem {
             DESC_OPERATOR => Ok(Self::DescOperator(DescOperator::new(prefix, value)?)),
             LIMIT_QUERY => Ok(Self::LimitQuery(LimitQuery::new(prefix, value)?)),
             OFFSET_QUERY => Ok(Self::OffsetQuery(OffsetQuery::new(prefix, value)?)),
+            CURSOR_QUERY => Ok(Self::CursorQuery(CursorQuery::new(prefix, value)?)),
+            DEPTH_QUERY => Ok(Self::DepthQuery(DepthQuery::new(prefix, value)?)),
+            DISTINCT_QUERY => Ok(Self::DistinctQuery(DistinctQuery::new(prefix, value)?)),
+            REPLACE_QUERY => Ok(Self::ReplaceQuery(ReplaceQuery::new(prefix, value)?)),
+            IF_VERSION_QUERY => Ok(Self::IfVersionQuery(IfVersionQuery::new(prefix, value)?)),
+            ASSERT_QUERY => Ok(Self::AssertQuery(AssertQuery::new(prefix, value)?)),
+            COUNT_QUERY => Ok(Self::CountAssertion(CountAssertion::new(prefix, value)?)),
+            SUM_ACCUMULATOR => Ok(Self::SumAccumulator(SumAccumulator::new(prefix, value)?)),
+            AVG_ACCUMULATOR => Ok(Self::AvgAccumulator(AvgAccumulator::new(prefix, value)?)),
+            MIN_ACCUMULATOR => Ok(Self::MinAccumulator(MinAccumulator::new(prefix, value)?)),
+            MAX_ACCUMULATOR => Ok(Self::MaxAccumulator(MaxAccumulator::new(prefix, value)?)),
+            PUSH_ACCUMULATOR => Ok(Self::PushAccumulator(PushAccumulator::new(prefix, value)?)),
+            FIRST_ACCUMULATOR => Ok(Self::FirstAccumulator(FirstAccumulator::new(
+                prefix, value,
+            )?)),
+            LAST_ACCUMULATOR => Ok(Self::LastAccumulator(LastAccumulator::new(prefix, value)?)),
             _ => Err(DBError::new("Unexpected modifier type")),
         }
     }
@@ -47,6 +110,20 @@ impl TySONModifier for ModifierItem {
             ModifierItem::DescOperator(o) => o.get_serialized_value(),
             ModifierItem::LimitQuery(o) => o.get_serialized_value(),
             ModifierItem::OffsetQuery(o) => o.get_serialized_value(),
+            ModifierItem::CursorQuery(o) => o.get_serialized_value(),
+            ModifierItem::DepthQuery(o) => o.get_serialized_value(),
+            ModifierItem::DistinctQuery(o) => o.get_serialized_value(),
+            ModifierItem::ReplaceQuery(o) => o.get_serialized_value(),
+            ModifierItem::IfVersionQuery(o) => o.get_serialized_value(),
+            ModifierItem::AssertQuery(o) => o.get_serialized_value(),
+            ModifierItem::CountAssertion(o) => o.get_serialized_value(),
+            ModifierItem::SumAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::AvgAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::MinAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::MaxAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::PushAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::FirstAccumulator(o) => o.get_serialized_value(),
+            ModifierItem::LastAccumulator(o) => o.get_serialized_value(),
         }
     }
 }
@@ -59,6 +136,20 @@ impl ModifierItem {
             ModifierItem::DescOperator(o) => o.get_value(),
             ModifierItem::LimitQuery(o) => o.get_value(),
             ModifierItem::OffsetQuery(o) => o.get_value(),
+            ModifierItem::CursorQuery(o) => o.get_value(),
+            ModifierItem::DepthQuery(o) => o.get_value(),
+            ModifierItem::DistinctQuery(o) => o.get_value(),
+            ModifierItem::ReplaceQuery(o) => o.get_value(),
+            ModifierItem::IfVersionQuery(o) => o.get_value(),
+            ModifierItem::AssertQuery(o) => o.get_value(),
+            ModifierItem::CountAssertion(o) => o.get_value(),
+            ModifierItem::SumAccumulator(o) => o.get_value(),
+            ModifierItem::AvgAccumulator(o) => o.get_value(),
+            ModifierItem::MinAccumulator(o) => o.get_value(),
+            ModifierItem::MaxAccumulator(o) => o.get_value(),
+            ModifierItem::PushAccumulator(o) => o.get_value(),
+            ModifierItem::FirstAccumulator(o) => o.get_value(),
+            ModifierItem::LastAccumulator(o) => o.get_value(),
         }
     }
 }
@@ -34,6 +34,10 @@ impl TySONPrimitive for BoolPrimitive {
 }
 
 impl BoolPrimitive {
+    pub fn get_value(&self) -> bool {
+        self.value
+    }
+
     pub fn create_true() -> Self {
         Self { value: true }
     }
@@ -11,7 +11,6 @@ use crate::DBError;
 pub struct Link {
     pub(crate) collection_name: String,
     id: Uuid,
-    links_to: Vec<Link>,
 }
 
 impl Link {
@@ -19,13 +18,8 @@ impl Link {
         Self {
             collection_name,
             id: Uuid::new_v4(),
-            links_to: vec![],
         }
     }
-
-    pub fn unlink(&mut self, link: &Link) {
-        self.links_to.retain(|x| x != link)
-    }
 }
 
 impl BaseTySONItemInterface for Link {
@@ -42,7 +36,6 @@ impl TySONPrimitive for Link {
         Ok(Self {
             collection_name: prefix,
             id: Uuid::from_str(value.as_str())?,
-            links_to: vec![],
         })
     }
 
@@ -1,17 +1,24 @@
 use crate::constants::{
-    BOOL, COLLECTION_NAME, DELETED, DELETE_QUERY, KEEP, NULL, NUMBER, PATH_TO_VALUE, ROOT, STRING,
-    UTS,
+    BOOL, COLLECTION_NAME, COUNT_ACCUMULATOR, DELETED, DELETE_QUERY, EXCLUDE, KEEP, LINK_ID, NULL,
+    NUMBER, PARAM, PATH_TO_VALUE, REFERENCED_BY_QUERY, ROOT, STEP_RESULT, STRING, UTS, VERSION,
 };
 use crate::data_types::primitives::bool::BoolPrimitive;
 use crate::data_types::primitives::deleted::DeletedPrimitive;
 use crate::data_types::primitives::link::Link;
 use crate::data_types::primitives::null::NullPrimitive;
 use crate::data_types::primitives::number::NumberPrimitive;
+use crate::data_types::primitives::param::Param;
 use crate::data_types::primitives::root::RootPrimitive;
+use crate::data_types::primitives::step_result::StepResult;
 use crate::data_types::primitives::string::StringPrimitive;
 use crate::data_types::primitives::unix_timestamp::UTSPrimitive;
 use crate::query::delete::query::DeleteQuery;
+use crate::query::group::operators::count::CountAccumulator;
+use crate::query::project::operators::exclude::ExcludePrimitive;
 use crate::query::project::operators::keep::KeepPrimitive;
+use crate::query::project::operators::link_id::LinkIdPrimitive;
+use crate::query::project::operators::version::VersionPrimitive;
+use crate::query::referenced_by::query::ReferencedByQuery;
 use crate::storage::common::collection_name::CollectionName;
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::primitive::TySONPrimitive;
@@ -22,8 +29,10 @@ pub mod deleted;
 pub mod link;
 mod null;
 pub mod number;
+pub mod param;
 pub mod path;
 pub mod root;
+pub mod step_result;
 pub mod string;
 pub mod unix_timestamp;
 
@@ -38,12 +47,19 @@ pub enum Primitive {
     DeletedPrimitive(DeletedPrimitive),
 
     CollectionName(CollectionName),
+    StepResult(StepResult),
+    Param(Param),
     PathToValue(PathToValue),
     RootPrimitive(RootPrimitive),
 
     DeleteQuery(DeleteQuery),
+    ReferencedByQuery(ReferencedByQuery),
+    CountAccumulator(CountAccumulator),
 
     KeepPrimitive(KeepPrimitive),
+    VersionPrimitive(VersionPrimitive),
+    ExcludePrimitive(ExcludePrimitive),
+    LinkIdPrimitive(LinkIdPrimitive),
 }
 
 impl Primitive {
@@ -57,15 +73,30 @@ impl Primitive {
             DELETED => Ok(Self::DeletedPrimitive(DeletedPrimitive::new(
                 prefix, value,
             )?)),
+            EXCLUDE => Ok(Self::ExcludePrimitive(ExcludePrimitive::new(
+                prefix, value,
+            )?)),
+            LINK_ID => Ok(Self::LinkIdPrimitive(LinkIdPrimitive::new(prefix, value)?)),
 
             COLLECTION_NAME => Ok(Self::CollectionName(CollectionName::new(prefix, value)?)),
+            STEP_RESULT => Ok(Self::StepResult(StepResult::new(prefix, value)?)),
+            PARAM => Ok(Self::Param(Param::new(prefix, value)?)),
 
             PATH_TO_VALUE => Ok(Self::PathToValue(PathToValue::new(prefix, value)?)),
             ROOT => Ok(Self::RootPrimitive(RootPrimitive::new(prefix, value)?)),
 
             DELETE_QUERY => Ok(Self::DeleteQuery(DeleteQuery::new(prefix, value)?)),
+            REFERENCED_BY_QUERY => Ok(Self::ReferencedByQuery(ReferencedByQuery::new(
+                prefix, value,
+            )?)),
+            COUNT_ACCUMULATOR => Ok(Self::CountAccumulator(CountAccumulator::new(
+                prefix, value,
+            )?)),
 
             KEEP => Ok(Self::KeepPrimitive(KeepPrimitive::new(prefix, value)?)),
+            VERSION => Ok(Self::VersionPrimitive(VersionPrimitive::new(
+                prefix, value,
+            )?)),
 
             _ => Ok(Self::Link(Link::new(prefix, value)?)),
         }
@@ -83,12 +114,19 @@ impl Primitive {
             Self::DeletedPrimitive(o) => o.serialize(),
 
             Self::CollectionName(o) => o.serialize(),
+            Self::StepResult(o) => o.serialize(),
+            Self::Param(o) => o.serialize(),
             Self::PathToValue(o) => o.serialize(),
             Self::RootPrimitive(o) => o.serialize(),
 
             Self::DeleteQuery(o) => o.serialize(),
+            Self::ReferencedByQuery(o) => o.serialize(),
+            Self::CountAccumulator(o) => o.serialize(),
 
             Self::KeepPrimitive(o) => o.serialize(),
+            Self::VersionPrimitive(o) => o.serialize(),
+            Self::ExcludePrimitive(o) => o.serialize(),
+            Self::LinkIdPrimitive(o) => o.serialize(),
         }
     }
 
@@ -104,12 +142,19 @@ impl Primitive {
             Self::DeletedPrimitive(o) => o.get_prefix(),
 
             Self::CollectionName(o) => o.get_prefix(),
+            Self::StepResult(o) => o.get_prefix(),
+            Self::Param(o) => o.get_prefix(),
             Self::PathToValue(o) => o.get_prefix(),
             Self::RootPrimitive(o) => o.get_prefix(),
 
             Self::DeleteQuery(o) => o.get_prefix(),
+            Self::ReferencedByQuery(o) => o.get_prefix(),
+            Self::CountAccumulator(o) => o.get_prefix(),
 
             Self::KeepPrimitive(o) => o.get_prefix(),
+            Self::VersionPrimitive(o) => o.get_prefix(),
+            Self::ExcludePrimitive(o) => o.get_prefix(),
+            Self::LinkIdPrimitive(o) => o.get_prefix(),
         }
     }
 }
@@ -48,6 +48,12 @@ impl NumberPrimitive {
             value: self.value + other.value,
         }
     }
+
+    pub fn mul(&self, other: &NumberPrimitive) -> Self {
+        Self {
+            value: self.value * other.value,
+        }
+    }
 }
 
 impl From<usize> for NumberPrimitive {
@@ -55,3 +61,9 @@ impl From<usize> for NumberPrimitive {
         Self { value: n as f64 }
     }
 }
+
+impl From<f64> for NumberPrimitive {
+    fn from(n: f64) -> Self {
+        Self { value: n }
+    }
+}
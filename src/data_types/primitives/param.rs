@@ -0,0 +1,51 @@
+use std::fmt::Debug;
+
+use crate::constants::PARAM;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::primitive::TySONPrimitive;
+use crate::DBError;
+
+/// Placeholder of a prepared query. `param|num:n|` is replaced with the `num` parameter,
+/// which must be a primitive with the `n` prefix.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct Param {
+    name: String,
+    kind: String,
+}
+
+impl BaseTySONItemInterface for Param {
+    fn get_prefix(&self) -> String {
+        PARAM.to_string()
+    }
+}
+
+impl TySONPrimitive for Param {
+    fn new(_: String, value: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value.split_once(':') {
+            Some((name, kind)) if !name.is_empty() && !kind.is_empty() => Ok(Self {
+                name: name.to_string(),
+                kind: kind.to_string(),
+            }),
+            _ => Err(DBError::new(
+                format!("Invalid parameter {}", value).as_str(),
+            )),
+        }
+    }
+
+    fn get_string_value(&self) -> String {
+        format!("{}:{}", self.name, self.kind)
+    }
+}
+
+impl Param {
+    pub fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn get_kind(&self) -> &str {
+        self.kind.as_str()
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::constants::PATH_TO_VALUE;
+use crate::constants::{PATH_SEPARATOR, PATH_TO_VALUE, WILDCARD_PATH};
 use crate::data_types::primitives::root::RootPrimitive;
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::primitive::TySONPrimitive;
@@ -34,6 +34,10 @@ impl PathToValue {
     // pub(crate) fn to_path(&self) -> Vec<String>{
     //     self.value.split(".").collect()
     // }
+
+    pub(crate) fn is_wildcard(&self) -> bool {
+        self.value.split(PATH_SEPARATOR).any(|p| p == WILDCARD_PATH)
+    }
 }
 
 pub enum Path {
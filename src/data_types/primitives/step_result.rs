@@ -0,0 +1,108 @@
+use std::fmt::Debug;
+
+use crate::constants::{RESPONSE_DATA, RESPONSE_IDS, STEP_RESULT};
+use crate::data_types::vector::storage::StorageVector;
+use crate::response::QueryResponse;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::primitive::TySONPrimitive;
+use crate::{DBError, Item, Link, MapItem, Primitive, TySONVector, VectorItem};
+
+/// Reference to the result of a previous item of the transaction.
+/// `$0` and `$0.data` point to the data of the first item,
+/// `$0.ids` to all the ids it returned and `$0.ids.1` to the second of them.
+///
+/// Every transaction item takes an index, including savepoints, rollbacks,
+/// prepared query and procedure definitions, cursor commands and the idempotency key.
+/// The result of `execute` and `call` is the result of the last step of the template.
+/// Savepoints, rollbacks, definitions, `close` and the rolled back items have no result.
+/// Steps of a template use the indexes of the calling transaction.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct StepResult {
+    value: String,
+    step: usize,
+    path: Vec<String>,
+}
+
+impl BaseTySONItemInterface for StepResult {
+    fn get_prefix(&self) -> String {
+        STEP_RESULT.to_string()
+    }
+}
+
+impl TySONPrimitive for StepResult {
+    fn new(_: String, value: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        let error = || DBError::new(format!("Invalid step result {}", value).as_str());
+        let mut parts = value.strip_prefix('$').ok_or_else(error)?.split('.');
+        let step = parts
+            .next()
+            .and_then(|o| o.parse::<usize>().ok())
+            .ok_or_else(error)?;
+        let path: Vec<String> = parts.map(|o| o.to_string()).collect();
+        match path.iter().map(|o| o.as_str()).collect::<Vec<&str>>()[..] {
+            [] | [RESPONSE_DATA] | [RESPONSE_IDS] => {}
+            [RESPONSE_IDS, index] if index.parse::<usize>().is_ok() => {}
+            _ => return Err(error()),
+        }
+        Ok(Self { value, step, path })
+    }
+
+    fn get_string_value(&self) -> String {
+        self.value.to_string()
+    }
+}
+
+impl StepResult {
+    fn get_ids(&self, data: &Item) -> Result<Vec<Link>, DBError> {
+        match data {
+            Item::Vector(VectorItem::ResponseIds(o)) => Ok(o
+                .get_items()
+                .iter()
+                .filter_map(|i| match i {
+                    Item::Primitive(Primitive::Link(l)) => Some(l.clone()),
+                    _ => None,
+                })
+                .collect()),
+            Item::Map(MapItem::ResponseObjects(o)) => {
+                Ok(o.values.iter().map(|(l, _)| l.clone()).collect())
+            }
+            _ => Err(DBError::new(
+                format!("Step result ${} contains no ids", self.step).as_str(),
+            )),
+        }
+    }
+
+    pub(crate) fn resolve(&self, results: &[Option<QueryResponse>]) -> Result<Item, DBError> {
+        let response = results
+            .get(self.step)
+            .and_then(|o| o.as_ref())
+            .ok_or_else(|| {
+                DBError::new(format!("Step result ${} is not available", self.step).as_str())
+            })?;
+        if self.path.first().map(|o| o.as_str()) != Some(RESPONSE_IDS) {
+            return Ok(response.data.clone());
+        }
+        let ids = self.get_ids(&response.data)?;
+        match self.path.get(1) {
+            Some(index) => {
+                let link = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| ids.get(i))
+                    .ok_or_else(|| {
+                        DBError::new(format!("Step result {} does not exist", self.value).as_str())
+                    })?;
+                Ok(Item::from(Primitive::Link(link.clone())))
+            }
+            None => {
+                let mut vector = StorageVector::new("".to_string())?;
+                for link in ids {
+                    vector.push(Item::from(Primitive::Link(link)))?;
+                }
+                Ok(vector.to_item())
+            }
+        }
+    }
+}
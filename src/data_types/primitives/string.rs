@@ -29,6 +29,12 @@ impl TySONPrimitive for StringPrimitive {
     }
 }
 
+impl StringPrimitive {
+    pub fn get_value(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
 impl From<&str> for StringPrimitive {
     fn from(v: &str) -> Self {
         Self {
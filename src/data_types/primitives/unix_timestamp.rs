@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::constants::UTS;
 use crate::tyson::item::BaseTySONItemInterface;
@@ -48,6 +49,15 @@ impl UTSPrimitive {
             value: self.value + other.value,
         }
     }
+
+    pub fn now() -> Result<Self, DBError> {
+        let duration = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| DBError::new("System time is before the unix epoch"))?;
+        Ok(Self {
+            value: duration.as_secs() as i64,
+        })
+    }
 }
 
 impl From<usize> for UTSPrimitive {
@@ -5,16 +5,21 @@ use crate::query::insert::query::InsertQuery;
 use crate::query::queryset::QuerySet;
 
 use crate::constants::{
-    AND_OPERATOR, FIND_QUERY, GET_QUERY, INSERT_QUERY, OR_OPERATOR, QUERY_SET, RESPONSE_IDS,
-    SORT_QUERY, STORAGE_VECTOR, UPDATE_QUERY,
+    ADD_EXPRESSION, AND_OPERATOR, CONCAT_EXPRESSION, COND_EXPRESSION, DIV_EXPRESSION,
+    FETCH_EXPRESSION, FIND_QUERY, GET_QUERY, INSERT_QUERY, MUL_EXPRESSION, OR_OPERATOR, QUERY_SET,
+    RESPONSE_IDS, SORT_QUERY, STORAGE_VECTOR, SUB_EXPRESSION, UPDATE_QUERY, UPSERT_QUERY,
 };
 use crate::data_types::item::Item;
 use crate::data_types::vector::storage::StorageVector;
 use crate::query::find::operators::and::AndOperator;
 use crate::query::find::operators::or::OrOperator;
 use crate::query::get::query::GetQuery;
+use crate::query::project::operators::arithmetic::ArithmeticExpression;
+use crate::query::project::operators::concat::ConcatExpression;
+use crate::query::project::operators::cond::CondExpression;
+use crate::query::project::operators::fetch::FetchExpression;
 use crate::query::sort::query::SortQuery;
-use crate::query::update::query::UpdateQuery;
+use crate::query::update::query::{UpdateQuery, UpsertQuery};
 use crate::response::ids::ResponseIds;
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::tyson::vector::TySONVector;
@@ -32,12 +37,19 @@ pub enum VectorItem {
     FindQuery(FindQuery),
     GetQuery(GetQuery),
     UpdateQuery(UpdateQuery),
+    UpsertQuery(UpsertQuery),
     SortQuery(SortQuery),
 
     // find operators
     AndOperator(AndOperator),
     OrOperator(OrOperator),
 
+    // project expressions
+    ConcatExpression(ConcatExpression),
+    ArithmeticExpression(ArithmeticExpression),
+    CondExpression(CondExpression),
+    FetchExpression(FetchExpression),
+
     // response
     ResponseIds(ResponseIds),
 }
@@ -53,12 +65,19 @@ impl BaseTySONItemInterface for VectorItem {
             VectorItem::FindQuery(_) => FIND_QUERY.to_string(),
             VectorItem::GetQuery(_) => GET_QUERY.to_string(),
             VectorItem::UpdateQuery(_) => UPDATE_QUERY.to_string(),
+            VectorItem::UpsertQuery(_) => UPSERT_QUERY.to_string(),
             VectorItem::SortQuery(_) => SORT_QUERY.to_string(),
 
             // FIND OPERATORS
             VectorItem::AndOperator(_) => AND_OPERATOR.to_string(),
             VectorItem::OrOperator(_) => OR_OPERATOR.to_string(),
 
+            // PROJECT EXPRESSIONS
+            VectorItem::ConcatExpression(_) => CONCAT_EXPRESSION.to_string(),
+            VectorItem::ArithmeticExpression(o) => o.get_prefix(),
+            VectorItem::CondExpression(_) => COND_EXPRESSION.to_string(),
+            VectorItem::FetchExpression(_) => FETCH_EXPRESSION.to_string(),
+
             // RESPONSE IDS
             VectorItem::ResponseIds(_) => RESPONSE_IDS.to_string(),
         }
@@ -81,12 +100,27 @@ impl TySONVector for VectorItem {
             FIND_QUERY => Ok(VectorItem::FindQuery(FindQuery::new("".to_string())?)),
             GET_QUERY => Ok(VectorItem::GetQuery(GetQuery::new("".to_string())?)),
             UPDATE_QUERY => Ok(VectorItem::UpdateQuery(UpdateQuery::new("".to_string())?)),
+            UPSERT_QUERY => Ok(VectorItem::UpsertQuery(UpsertQuery::new("".to_string())?)),
             SORT_QUERY => Ok(VectorItem::SortQuery(SortQuery::new("".to_string())?)),
 
             // FIND OPERATORS
             AND_OPERATOR => Ok(VectorItem::AndOperator(AndOperator::new("".to_string())?)),
             OR_OPERATOR => Ok(VectorItem::OrOperator(OrOperator::new("".to_string())?)),
 
+            // PROJECT EXPRESSIONS
+            CONCAT_EXPRESSION => Ok(VectorItem::ConcatExpression(ConcatExpression::new(
+                "".to_string(),
+            )?)),
+            ADD_EXPRESSION | SUB_EXPRESSION | MUL_EXPRESSION | DIV_EXPRESSION => Ok(
+                VectorItem::ArithmeticExpression(ArithmeticExpression::new(prefix)?),
+            ),
+            COND_EXPRESSION => Ok(VectorItem::CondExpression(CondExpression::new(
+                "".to_string(),
+            )?)),
+            FETCH_EXPRESSION => Ok(VectorItem::FetchExpression(FetchExpression::new(
+                "".to_string(),
+            )?)),
+
             // RESPONSE
             RESPONSE_IDS => Ok(VectorItem::ResponseIds(ResponseIds::new("".to_string())?)),
 
@@ -105,12 +139,19 @@ impl TySONVector for VectorItem {
             VectorItem::FindQuery(o) => o.push(item),
             VectorItem::GetQuery(o) => o.push(item),
             VectorItem::UpdateQuery(o) => o.push(item),
+            VectorItem::UpsertQuery(o) => o.push(item),
             VectorItem::SortQuery(o) => o.push(item),
 
             // FIND OPERATORS
             VectorItem::AndOperator(o) => o.push(item),
             VectorItem::OrOperator(o) => o.push(item),
 
+            // PROJECT EXPRESSIONS
+            VectorItem::ConcatExpression(o) => o.push(item),
+            VectorItem::ArithmeticExpression(o) => o.push(item),
+            VectorItem::CondExpression(o) => o.push(item),
+            VectorItem::FetchExpression(o) => o.push(item),
+
             // RESPONSE
             VectorItem::ResponseIds(o) => o.push(item),
         }
@@ -126,12 +167,19 @@ impl TySONVector for VectorItem {
             VectorItem::FindQuery(o) => o.get_items(),
             VectorItem::GetQuery(o) => o.get_items(),
             VectorItem::UpdateQuery(o) => o.get_items(),
+            VectorItem::UpsertQuery(o) => o.get_items(),
             VectorItem::SortQuery(o) => o.get_items(),
 
             // FIND OPERATORS
             VectorItem::AndOperator(o) => o.get_items(),
             VectorItem::OrOperator(o) => o.get_items(),
 
+            // PROJECT EXPRESSIONS
+            VectorItem::ConcatExpression(o) => o.get_items(),
+            VectorItem::ArithmeticExpression(o) => o.get_items(),
+            VectorItem::CondExpression(o) => o.get_items(),
+            VectorItem::FetchExpression(o) => o.get_items(),
+
             // RESPONSE
             VectorItem::ResponseIds(o) => o.get_items(),
         }
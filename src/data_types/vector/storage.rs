@@ -1,4 +1,4 @@
-use crate::constants::STORAGE_VECTOR;
+use crate::constants::{LAST_INDEX_PATH, STORAGE_VECTOR};
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::{DBError, Item, TySONVector, VectorItem};
 
@@ -33,15 +33,29 @@ impl TySONVector for StorageVector {
 }
 
 impl StorageVector {
+    fn get_index(&self, k: &str) -> Option<usize> {
+        if k == LAST_INDEX_PATH {
+            return self.items.len().checked_sub(1);
+        }
+        k.parse::<usize>().ok()
+    }
+
     pub(crate) fn get_by_str(&self, k: &str) -> Result<Option<&Item>, DBError> {
-        match k.parse::<usize>() {
-            Ok(num) => Ok(self.items.get(num)),
-            Err(_) => Ok(None),
+        match self.get_index(k) {
+            Some(num) => Ok(self.items.get(num)),
+            None => Ok(None),
         }
     }
 
     pub(crate) fn replace_by_string(&mut self, k: String, item: Item) -> Result<bool, DBError> {
-        let index = k.as_str().parse::<usize>()?;
+        let index = if k == LAST_INDEX_PATH {
+            match self.items.len().checked_sub(1) {
+                Some(index) => index,
+                None => return Err(DBError::new("Vector is empty")),
+            }
+        } else {
+            k.as_str().parse::<usize>()?
+        };
         if self.items.len() > index {
             self.items[index] = item;
             Ok(true)
@@ -8,15 +8,26 @@ use uuid::ParseError;
 #[derive(Debug)]
 pub struct DBError {
     pub(crate) msg: String,
+    pub(crate) step: Option<usize>,
+    pub(crate) query: Option<usize>,
 }
 
 impl DBError {
     pub(crate) fn new(msg: &str) -> Self {
         Self {
             msg: msg.to_string(),
+            step: None,
+            query: None,
         }
     }
 
+    /// Points the error to the failed query of the transaction step
+    pub(crate) fn at(mut self, step: usize, query: usize) -> Self {
+        self.step = Some(step);
+        self.query = Some(query);
+        self
+    }
+
     pub fn unexpected_parsing() -> Self {
         Self::new("Unexpected parsing error")
     }
@@ -24,9 +35,7 @@ impl DBError {
 
 impl From<Error<Rule>> for DBError {
     fn from(error: Error<Rule>) -> Self {
-        Self {
-            msg: error.to_string(),
-        }
+        Self::new(error.to_string().as_str())
     }
 }
 
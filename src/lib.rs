@@ -36,7 +36,9 @@ pub fn run() {
     println!("Starting...");
     let config = Config::new();
 
-    let mut storage = Storage::new(config.wh_path).unwrap(); // TODO fix this
+    let mut storage = Storage::new(config.wh_path)
+        .unwrap()
+        .with_fetch_depth(config.fetch_depth); // TODO fix this
 
     let context = zmq::Context::new();
     let responder = context.socket(zmq::REP).unwrap();
@@ -0,0 +1,3 @@
+pub mod operators;
+pub mod processor;
+pub mod query;
@@ -0,0 +1,46 @@
+use std::fmt::Debug;
+
+use crate::constants::COUNT_QUERY;
+use crate::data_types::modifier::ModifierItem;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CountAssertion {
+    value: Box<Item>,
+}
+
+impl BaseTySONItemInterface for CountAssertion {
+    fn get_prefix(&self) -> String {
+        COUNT_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for CountAssertion {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(Primitive::NumberPrimitive(_)) => Ok(Self {
+                value: Box::new(value),
+            }),
+            _ => Err(DBError::new("Count assertion can contain only a number")),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.value.serialize()
+    }
+}
+
+impl CountAssertion {
+    pub fn get_value(&self) -> &Item {
+        self.value.as_ref()
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Modifier(ModifierItem::CountAssertion(self))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::constants::NULL;
+use crate::data_types::modifier::ModifierItem;
+use crate::query::assert::query::AssertQuery;
+use crate::query::find::compare::{compare, Res};
+use crate::query::find::processor::get_ids_list;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Link, Primitive, Storage, TySONPrimitive};
+
+fn check(
+    storage: &Storage,
+    query: &AssertQuery,
+    ids: &[Link],
+    insert_buf: &InsertBuffer,
+) -> Result<(), DBError> {
+    match query.get_value() {
+        Item::Modifier(ModifierItem::CountAssertion(o)) => {
+            if let Item::Primitive(Primitive::NumberPrimitive(n)) = o.get_value() {
+                if ids.len() as f64 != n.get_value() {
+                    return Err(DBError::new(
+                        format!(
+                            "expected count {}, found {}",
+                            n.get_string_value(),
+                            ids.len()
+                        )
+                        .as_str(),
+                    ));
+                }
+            }
+        }
+        op => {
+            for id in ids {
+                if compare(op, id, storage, insert_buf)? != Res::True {
+                    return Err(DBError::new(
+                        format!("object {} does not match", id.get_string_value()).as_str(),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks the found objects or the whole collection, if it is the first query of the step.
+/// The failed assertion is reported with the indexes of its transaction item and query.
+pub fn assert(
+    storage: &Storage,
+    collection_name: String,
+    query: &AssertQuery,
+    filter_buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+    step: usize,
+    iteration: i32,
+) -> Result<QueryResponse, DBError> {
+    let ids = if iteration == 1 {
+        get_ids_list(storage, collection_name, insert_buf)
+    } else {
+        filter_buf.ids.clone()
+    };
+    if let Err(e) = check(storage, query, &ids, insert_buf) {
+        return Err(
+            DBError::new(format!("Assertion failed: {}", e.msg).as_str())
+                .at(step, iteration as usize - 1),
+        );
+    }
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(ids.len()));
+    Ok(QueryResponse::new(data, meta, QueryStatus::NotFetched))
+}
@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use crate::constants::ASSERT_QUERY;
+use crate::data_types::modifier::ModifierItem;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, VectorItem};
+
+/// Checks the found objects and aborts the transaction if the check fails.
+/// It can contain a count assertion or a find operator, which all the found objects must match.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AssertQuery {
+    value: Box<Item>,
+}
+
+impl BaseTySONItemInterface for AssertQuery {
+    fn get_prefix(&self) -> String {
+        ASSERT_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for AssertQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Modifier(ModifierItem::CountAssertion(_))
+            | Item::Modifier(ModifierItem::NotOperator(_))
+            | Item::Vector(VectorItem::AndOperator(_))
+            | Item::Vector(VectorItem::OrOperator(_))
+            | Item::Map(_) => Ok(Self {
+                value: Box::new(value),
+            }),
+            _ => Err(DBError::new(
+                "Assert query can contain only a count assertion or a find operator",
+            )),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.value.serialize()
+    }
+}
+
+impl AssertQuery {
+    pub fn get_value(&self) -> &Item {
+        self.value.as_ref()
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Modifier(ModifierItem::AssertQuery(self))
+    }
+}
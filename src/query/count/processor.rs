@@ -0,0 +1,23 @@
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::query::find::processor::get_ids_list;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Primitive, Storage};
+
+pub fn count(
+    storage: &Storage,
+    collection_name: String,
+    buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    let count = if is_first {
+        get_ids_list(storage, collection_name, insert_buf).len()
+    } else {
+        buf.ids.len()
+    };
+    let data = Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(count)));
+    let meta = Meta::FindMeta(FindMeta::new(count));
+    Ok(QueryResponse::new(data, meta, QueryStatus::Ready))
+}
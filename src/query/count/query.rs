@@ -0,0 +1,16 @@
+use crate::query::group::operators::count::CountAccumulator;
+use crate::query::operations::QueryOperation;
+use crate::{Item, Primitive};
+
+/// The `count` step uses the same primitive as the group accumulator.
+pub type CountQuery = CountAccumulator;
+
+impl CountQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Primitive(Primitive::CountAccumulator(self))
+    }
+}
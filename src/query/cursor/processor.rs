@@ -0,0 +1,36 @@
+use crate::constants::NULL;
+use crate::query::cursor::query::CursorQuery;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::FilterBuffer;
+use crate::{DBError, Item, Link, Primitive};
+
+pub(crate) fn get_batch_size(query: &CursorQuery) -> Result<usize, DBError> {
+    match query.get_value() {
+        Item::Primitive(Primitive::NumberPrimitive(n))
+            if n.get_value() >= 1.0 && n.get_value().fract() == 0.0 =>
+        {
+            Ok(n.get_value() as usize)
+        }
+        _ => Err(DBError::new("Cursor batch size must be a positive integer")),
+    }
+}
+
+/// Leaves the first batch in the buffer and returns the rest of the ids
+pub fn cursor(
+    query: &CursorQuery,
+    buf: &mut FilterBuffer,
+) -> Result<(QueryResponse, Vec<Link>), DBError> {
+    let size = get_batch_size(query)?;
+    let rest = if size < buf.ids.len() {
+        buf.ids.split_off(size)
+    } else {
+        vec![]
+    };
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(buf.ids.len()));
+    Ok((
+        QueryResponse::new(data, meta, QueryStatus::NotFetched),
+        rest,
+    ))
+}
@@ -0,0 +1,51 @@
+use crate::constants::CURSOR_QUERY;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+/// Returns the first batch of the found objects and keeps the rest for the `more` steps
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CursorQuery {
+    expr: Box<Item>,
+}
+
+impl BaseTySONItemInterface for CursorQuery {
+    fn get_prefix(&self) -> String {
+        CURSOR_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for CursorQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match &value {
+            // Placeholders are replaced with numbers before the query runs
+            Item::Primitive(Primitive::NumberPrimitive(_))
+            | Item::Primitive(Primitive::Param(_))
+            | Item::Primitive(Primitive::StepResult(_)) => Ok(Self {
+                expr: Box::new(value),
+            }),
+            _ => Err(DBError::new("Cursor supports only numbers as a batch size")),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.expr.serialize()
+    }
+}
+
+impl CursorQuery {
+    pub fn get_value(&self) -> &Item {
+        self.expr.as_ref()
+    }
+
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![
+            QueryOperation::DepthOperation,
+            QueryOperation::ProjectOperation,
+        ]
+    }
+}
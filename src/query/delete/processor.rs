@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use crate::constants::{DELETED, NULL, ROOT};
+use crate::query::find::processor::get_ids_list;
+use crate::query::on_delete::processor::get_delete_policy;
+use crate::query::on_delete::query::DeletePolicy;
+use crate::query::update::operators::set::SetOperator;
+use crate::query::update::processor::update;
+use crate::query::update::query::UpdateQuery;
+use crate::response::meta::{DeleteMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Link, MapItem, PathToValue, Primitive, Storage, TySONPrimitive};
+
+fn set_value(
+    storage: &Storage,
+    ids: Vec<Link>,
+    key: Primitive,
+    value: &str,
+    insert_buf: &mut InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let set_operator = Item::Map(MapItem::SetOperator(SetOperator {
+        values: vec![(
+            key,
+            Item::Primitive(Primitive::new(value.to_string(), "".to_string())?),
+        )],
+    }));
+    let query = UpdateQuery {
+        items: vec![set_operator],
+    };
+    let mut filter_buf = FilterBuffer::new();
+    filter_buf.update(ids);
+    update(storage, &query, insert_buf, &filter_buf)
+}
+
+/// Applies delete policies of the documents which link to the deleted ones.
+/// Everything is written to the insert buffer, so a restricted delete
+/// leaves the transaction without changes.
+fn apply_policies(
+    storage: &Storage,
+    ids: &[Link],
+    dropped_collection: Option<&str>,
+    insert_buf: &mut InsertBuffer,
+) -> Result<(), DBError> {
+    let mut deleted: HashSet<Link> = ids.iter().cloned().collect();
+    let mut queue: Vec<Link> = ids.to_vec();
+    let mut cascaded: Vec<Link> = vec![];
+    let mut nulled: Vec<(Link, String)> = vec![];
+    let mut restricted: Vec<(Link, String)> = vec![];
+    while let Some(id) = queue.pop() {
+        for (link, path) in storage.get_references(&id, insert_buf)? {
+            if deleted.contains(&link) || dropped_collection == Some(link.collection_name.as_str())
+            {
+                continue;
+            }
+            match get_delete_policy(storage, &link.collection_name, &path, insert_buf)? {
+                Some(DeletePolicy::Restrict) => restricted.push((link, path)),
+                Some(DeletePolicy::Cascade) => {
+                    deleted.insert(link.clone());
+                    queue.push(link.clone());
+                    cascaded.push(link);
+                }
+                Some(DeletePolicy::SetNull) => nulled.push((link, path)),
+                None => {}
+            }
+        }
+    }
+    for (link, path) in &restricted {
+        if !deleted.contains(link) {
+            return Err(DBError::new(
+                format!(
+                    "Delete is restricted: {} objects link to it by {}",
+                    link.collection_name, path
+                )
+                .as_str(),
+            ));
+        }
+    }
+    for (link, path) in nulled {
+        if deleted.contains(&link) {
+            continue;
+        }
+        let key = if path == ROOT {
+            Primitive::new(ROOT.to_string(), "".to_string())?
+        } else {
+            Primitive::PathToValue(PathToValue::new("".to_string(), path)?)
+        };
+        set_value(storage, vec![link], key, NULL, insert_buf)?;
+    }
+    if !cascaded.is_empty() {
+        let key = Primitive::new(ROOT.to_string(), "".to_string())?;
+        set_value(storage, cascaded, key, DELETED, insert_buf)?;
+    }
+    Ok(())
+}
+
+pub fn delete(
+    storage: &Storage,
+    collection_name: String,
+    filter_buf: &FilterBuffer,
+    insert_buf: &mut InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    if is_first {
+        let ids = get_ids_list(storage, collection_name.clone(), insert_buf);
+        apply_policies(storage, &ids, Some(collection_name.as_str()), insert_buf)?;
+        insert_buf.add_collection_to_drop(collection_name);
+        let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+        let meta = Meta::DeleteMeta(DeleteMeta::new(0_usize));
+        Ok(QueryResponse::new(data, meta, QueryStatus::Ready))
+    } else {
+        apply_policies(storage, &filter_buf.ids, None, insert_buf)?;
+        let key = Primitive::new(ROOT.to_string(), "".to_string())?;
+        set_value(storage, filter_buf.ids.clone(), key, DELETED, insert_buf)
+    }
+}
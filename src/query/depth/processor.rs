@@ -0,0 +1,29 @@
+use crate::constants::{FETCH_DEPTH_LIMIT, NULL};
+use crate::query::depth::query::DepthQuery;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::FilterBuffer;
+use crate::{DBError, Item, Primitive};
+
+pub(crate) fn get_depth(query: &DepthQuery) -> Result<usize, DBError> {
+    match query.get_value() {
+        Item::Primitive(Primitive::NumberPrimitive(n))
+            if n.get_value() >= 0.0
+                && n.get_value() <= FETCH_DEPTH_LIMIT as f64
+                && n.get_value().fract() == 0.0 =>
+        {
+            Ok(n.get_value() as usize)
+        }
+        _ => Err(DBError::new("Depth is out of range")),
+    }
+}
+
+pub fn depth(query: &DepthQuery, buf: &FilterBuffer) -> Result<(QueryResponse, usize), DBError> {
+    let depth = get_depth(query)?;
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(buf.ids.len()));
+    Ok((
+        QueryResponse::new(data, meta, QueryStatus::NotFetched),
+        depth,
+    ))
+}
@@ -0,0 +1,51 @@
+use crate::constants::DEPTH_QUERY;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+/// Sets how many links of the found objects are expanded
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DepthQuery {
+    expr: Box<Item>,
+}
+
+impl BaseTySONItemInterface for DepthQuery {
+    fn get_prefix(&self) -> String {
+        DEPTH_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for DepthQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match &value {
+            // Placeholders are replaced with numbers before the query runs
+            Item::Primitive(Primitive::NumberPrimitive(_))
+            | Item::Primitive(Primitive::Param(_))
+            | Item::Primitive(Primitive::StepResult(_)) => Ok(Self {
+                expr: Box::new(value),
+            }),
+            _ => Err(DBError::new("Depth supports only numbers as a parameter")),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.expr.serialize()
+    }
+}
+
+impl DepthQuery {
+    pub fn get_value(&self) -> &Item {
+        self.expr.as_ref()
+    }
+
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![
+            QueryOperation::CursorOperation,
+            QueryOperation::ProjectOperation,
+        ]
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use crate::constants::STORAGE_VECTOR;
+use crate::query::distinct::query::DistinctQuery;
+use crate::query::find::processor::get_ids_list;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Primitive, Storage, TySONVector, VectorItem};
+
+pub fn distinct(
+    storage: &Storage,
+    collection_name: String,
+    query: &DistinctQuery,
+    buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    let ids = if is_first {
+        get_ids_list(storage, collection_name, insert_buf)
+    } else {
+        buf.ids.clone()
+    };
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut data = VectorItem::new(STORAGE_VECTOR.to_string())?;
+    for id in &ids {
+        let values = match query.get_value() {
+            Item::Primitive(Primitive::PathToValue(path)) => {
+                storage.get_values_by_path(path, id, insert_buf)?
+            }
+            _ => vec![storage.resolve_link(id, insert_buf)?.1],
+        };
+        for value in values {
+            // only primitive values are compared, containers are skipped
+            if let Item::Primitive(pr) = &value {
+                if seen.insert(pr.serialize()) {
+                    data.push(value)?;
+                }
+            }
+        }
+    }
+    let meta = Meta::FindMeta(FindMeta::new(seen.len()));
+    Ok(QueryResponse::new(
+        Item::from(data),
+        meta,
+        QueryStatus::Ready,
+    ))
+}
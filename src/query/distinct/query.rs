@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use crate::constants::DISTINCT_QUERY;
+use crate::data_types::modifier::ModifierItem;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DistinctQuery {
+    expr: Box<Item>,
+}
+
+impl BaseTySONItemInterface for DistinctQuery {
+    fn get_prefix(&self) -> String {
+        DISTINCT_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for DistinctQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(Primitive::PathToValue(_))
+            | Item::Primitive(Primitive::RootPrimitive(_)) => Ok(Self {
+                expr: Box::new(value),
+            }),
+            _ => Err(DBError::new(
+                "Distinct query can contain only paths or root",
+            )),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.expr.serialize()
+    }
+}
+
+impl DistinctQuery {
+    pub fn get_value(&self) -> &Item {
+        self.expr.as_ref()
+    }
+
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Modifier(ModifierItem::DistinctQuery(self))
+    }
+}
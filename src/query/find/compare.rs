@@ -1,5 +1,11 @@
+use std::collections::HashSet;
+
 use crate::data_types::modifier::ModifierItem;
-use crate::{DBError, Item, Link, MapItem, Primitive, Storage, TySONVector, VectorItem};
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::{
+    DBError, Item, Link, MapItem, PathToValue, Primitive, Storage, TySONMap, TySONVector,
+    VectorItem,
+};
 
 use crate::storage::buffer::InsertBuffer;
 
@@ -51,35 +57,299 @@ fn prepare_item(
     }
 }
 
+// Wildcard paths can point to many values. The operators are applied to all of them
+// and match if any of the values matches.
+fn prepare_items(
+    item: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<Option<Primitive>>, DBError> {
+    match item {
+        Primitive::PathToValue(o) if o.is_wildcard() => {
+            let mut res: Vec<Option<Primitive>> = vec![];
+            for value in storage.get_values_by_path(o, id, insert_buf)? {
+                match value {
+                    Item::Primitive(val) => res.push(prepare_item(&val, id, storage, insert_buf)?),
+                    _ => res.push(None),
+                }
+            }
+            Ok(res)
+        }
+        _ => Ok(vec![prepare_item(item, id, storage, insert_buf)?]),
+    }
+}
+
+fn get_items(
+    key: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<Item>, DBError> {
+    match key {
+        Primitive::PathToValue(o) if o.is_wildcard() => {
+            storage.get_values_by_path(o, id, insert_buf)
+        }
+        Primitive::PathToValue(o) => {
+            match storage.get_value_by_path(o.clone(), id.clone(), insert_buf)? {
+                Some(i) => Ok(i.value.into_iter().collect()),
+                None => Ok(vec![]),
+            }
+        }
+        Primitive::RootPrimitive(_) => match insert_buf.items.get(id) {
+            Some(v) => Ok(vec![v.clone()]),
+            None => Ok(vec![storage.get_value_by_link(id)?]),
+        },
+        _ => Err(DBError::new("Only paths and root can be checked")),
+    }
+}
+
+fn prepare_strings(
+    item: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<String>, DBError> {
+    let mut res: Vec<String> = vec![];
+    for prepared in prepare_items(item, id, storage, insert_buf)? {
+        if let Some(Primitive::StringPrimitive(o)) = prepared {
+            res.push(o.get_value().to_string());
+        }
+    }
+    Ok(res)
+}
+
+fn contains(
+    left: &Primitive,
+    right: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    let new_r = prepare_item(right, id, storage, insert_buf)?;
+    for found in get_items(left, id, storage, insert_buf)? {
+        match found {
+            Item::Vector(VectorItem::StorageVector(v)) => {
+                for element in v.get_items() {
+                    let (_, value) = storage.resolve_link(&element.to_link()?, insert_buf)?;
+                    if let Item::Primitive(pr) = value {
+                        if compare_prepared(&Some(pr), &new_r) == CompareResult::Equal {
+                            return Ok(true);
+                        }
+                    }
+                }
+            }
+            Item::Primitive(pr) => {
+                if let (Some(Primitive::StringPrimitive(l)), Some(Primitive::StringPrimitive(r))) =
+                    (prepare_item(&pr, id, storage, insert_buf)?, &new_r)
+                {
+                    if l.get_value().contains(r.get_value()) {
+                        return Ok(true);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(false)
+}
+
+fn any(
+    left: &Primitive,
+    expr: &Item,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    for found in get_items(left, id, storage, insert_buf)? {
+        if let Item::Vector(VectorItem::StorageVector(v)) = found {
+            for element in v.get_items() {
+                // the expression is applied to each element as to a separate object,
+                // so `root` and relative paths point inside the element
+                let (element_id, _) = storage.resolve_link(&element.to_link()?, insert_buf)?;
+                if check_bool(expr, &element_id, storage, insert_buf)? == Res::True {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Returns the objects, which the path of the object links to. Vectors of links are flattened
+pub(crate) fn get_linked_ids(
+    path: &PathToValue,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<Link>, DBError> {
+    let mut res: Vec<Link> = vec![];
+    for (link, item) in storage.get_linked_values_by_path(path, id, insert_buf)? {
+        match item {
+            Item::Vector(VectorItem::StorageVector(v)) => {
+                for element in v.get_items() {
+                    let (element_id, _) = storage.resolve_link(&element.to_link()?, insert_buf)?;
+                    res.push(element_id);
+                }
+            }
+            _ => res.push(link),
+        }
+    }
+    Ok(res)
+}
+
+fn via(
+    left: &Primitive,
+    expr: &Item,
+    depth: usize,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    let path = match left {
+        Primitive::PathToValue(o) => o,
+        _ => return Err(DBError::new("Only paths can be followed")),
+    };
+    let mut visited: HashSet<Link> = HashSet::new();
+    visited.insert(id.clone());
+    let mut sources: Vec<Link> = vec![id.clone()];
+    for _ in 0..depth {
+        let mut targets: Vec<Link> = vec![];
+        for source in &sources {
+            targets.extend(get_linked_ids(path, source, storage, insert_buf)?);
+        }
+        let mut next_sources: Vec<Link> = vec![];
+        for target in targets {
+            // cycles are cut here, each object is checked once
+            if !visited.insert(target.clone()) {
+                continue;
+            }
+            if check_bool(expr, &target, storage, insert_buf)? == Res::True {
+                return Ok(true);
+            }
+            next_sources.push(target);
+        }
+        if next_sources.is_empty() {
+            break;
+        }
+        sources = next_sources;
+    }
+    Ok(false)
+}
+
+fn size_values(expr: &Item) -> Result<(Vec<&Primitive>, Vec<CompareResult>), DBError> {
+    let (values, expected) = match expr {
+        Item::Primitive(pr) => return Ok((vec![pr], vec![CompareResult::Equal])),
+        Item::Map(MapItem::EqOperator(o)) => (o.get_values(), vec![CompareResult::Equal]),
+        Item::Map(MapItem::NeqOperator(o)) => (
+            o.get_values(),
+            vec![CompareResult::Greater, CompareResult::Less],
+        ),
+        Item::Map(MapItem::GtOperator(o)) => (o.get_values(), vec![CompareResult::Greater]),
+        Item::Map(MapItem::GteOperator(o)) => (
+            o.get_values(),
+            vec![CompareResult::Greater, CompareResult::Equal],
+        ),
+        Item::Map(MapItem::LtOperator(o)) => (o.get_values(), vec![CompareResult::Less]),
+        Item::Map(MapItem::LteOperator(o)) => (
+            o.get_values(),
+            vec![CompareResult::Less, CompareResult::Equal],
+        ),
+        _ => return Err(DBError::new("Unsupported size operator")),
+    };
+    let mut res: Vec<&Primitive> = vec![];
+    for (k, v) in values {
+        match k {
+            Primitive::RootPrimitive(_) => res.push(v),
+            _ => return Err(DBError::new("Size can be compared by root only")),
+        }
+    }
+    Ok((res, expected))
+}
+
+fn size(
+    left: &Primitive,
+    expr: &Item,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    let (values, expected) = size_values(expr)?;
+    for found in get_items(left, id, storage, insert_buf)? {
+        let found_size = match found {
+            Item::Vector(v) => v.get_items().len(),
+            Item::Map(m) => m.get_items().len(),
+            _ => continue,
+        };
+        let found_size = Some(Primitive::NumberPrimitive(NumberPrimitive::from(
+            found_size,
+        )));
+        if values
+            .iter()
+            .all(|v| expected.contains(&compare_prepared(&found_size, &Some((*v).clone()))))
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 fn compare_primitives(
     left: &Primitive,
     right: &Primitive,
     id: &Link,
     storage: &Storage,
     insert_buf: &InsertBuffer,
-) -> Result<CompareResult, DBError> {
-    let new_l = prepare_item(left, id, storage, insert_buf)?;
+) -> Result<Vec<CompareResult>, DBError> {
     let new_r = prepare_item(right, id, storage, insert_buf)?;
+    let mut res: Vec<CompareResult> = vec![];
+    for new_l in prepare_items(left, id, storage, insert_buf)? {
+        res.push(compare_prepared(&new_l, &new_r));
+    }
+    Ok(res)
+}
+
+fn compare_prepared(new_l: &Option<Primitive>, new_r: &Option<Primitive>) -> CompareResult {
     match (new_l, new_r) {
-        (_, None) => Ok(CompareResult::CanNotCompare),
-        (None, _) => Ok(CompareResult::CanNotCompare),
+        (_, None) => CompareResult::CanNotCompare,
+        (None, _) => CompareResult::CanNotCompare,
         (Some(l), Some(r)) => {
-            return if l.get_prefix() != r.get_prefix() {
-                Ok(CompareResult::CanNotCompare)
+            if l.get_prefix() != r.get_prefix() {
+                CompareResult::CanNotCompare
             } else if l == r {
-                Ok(CompareResult::Equal)
+                CompareResult::Equal
             } else if l > r {
-                Ok(CompareResult::Greater)
+                CompareResult::Greater
             } else if l < r {
-                Ok(CompareResult::Less)
+                CompareResult::Less
             } else {
-                Ok(CompareResult::CanNotCompare)
+                CompareResult::CanNotCompare
             }
         }
     }
 }
 
-fn check_bool(
+fn is_in(
+    left: &Primitive,
+    options: &Vec<Primitive>,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    // the left side is resolved once and matched against every option
+    let lefts = prepare_items(left, id, storage, insert_buf)?;
+    for option in options {
+        let new_r = prepare_item(option, id, storage, insert_buf)?;
+        for new_l in &lefts {
+            if compare_prepared(new_l, &new_r) == CompareResult::Equal {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+pub(crate) fn check_bool(
     item: &Item,
     id: &Link,
     storage: &Storage,
@@ -110,7 +380,7 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res != CompareResult::Equal {
+                if !compare_res.contains(&CompareResult::Equal) {
                     return Ok(Res::False);
                 }
             }
@@ -122,7 +392,7 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res == CompareResult::Equal {
+                if compare_res.contains(&CompareResult::Equal) {
                     return Ok(Res::False);
                 }
             }
@@ -134,7 +404,7 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res != CompareResult::Greater {
+                if !compare_res.contains(&CompareResult::Greater) {
                     return Ok(Res::False);
                 }
             }
@@ -146,7 +416,9 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res != CompareResult::Greater && compare_res != CompareResult::Equal {
+                if !compare_res.contains(&CompareResult::Greater)
+                    && !compare_res.contains(&CompareResult::Equal)
+                {
                     return Ok(Res::False);
                 }
             }
@@ -158,7 +430,7 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res != CompareResult::Less {
+                if !compare_res.contains(&CompareResult::Less) {
                     return Ok(Res::False);
                 }
             }
@@ -170,7 +442,102 @@ pub fn compare(
                 // if compare_res == CompareResult::CanNotCompare {
                 //     return Ok(Res::None);
                 // } else
-                if compare_res != CompareResult::Less && compare_res != CompareResult::Equal {
+                if !compare_res.contains(&CompareResult::Less)
+                    && !compare_res.contains(&CompareResult::Equal)
+                {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::InOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if !is_in(k, v, id, storage, insert_buf)? {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::NinOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if is_in(k, v, id, storage, insert_buf)? {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::ExistsOperator(o)) => {
+            for (k, v) in o.get_values() {
+                let found = get_items(k, id, storage, insert_buf)?;
+                if found.is_empty() == v {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::TypeOperator(o)) => {
+            for (k, v) in o.get_values() {
+                let found = get_items(k, id, storage, insert_buf)?;
+                if !found.iter().any(|i| i.get_prefix() == v) {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::StartsWithOperator(o)) => {
+            for (k, v) in o.get_values() {
+                let found = prepare_strings(k, id, storage, insert_buf)?;
+                if !found.iter().any(|i| i.starts_with(v)) {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::ContainsOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if !contains(k, v, id, storage, insert_buf)? {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::IeqOperator(o)) => {
+            for (k, v) in o.get_values() {
+                let found = prepare_strings(k, id, storage, insert_buf)?;
+                if !found.iter().any(|i| i.to_lowercase() == v.to_lowercase()) {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::RegexOperator(o)) => {
+            for (k, v) in o.get_values() {
+                let found = prepare_strings(k, id, storage, insert_buf)?;
+                if !found.iter().any(|i| v.is_match(i.as_str())) {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::AnyOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if !any(k, v, id, storage, insert_buf)? {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::SizeOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if !size(k, v, id, storage, insert_buf)? {
+                    return Ok(Res::False);
+                }
+            }
+            Ok(Res::True)
+        }
+        Item::Map(MapItem::ViaOperator(o)) => {
+            for (k, v) in o.get_values() {
+                if !via(k, v, o.get_depth(), id, storage, insert_buf)? {
                     return Ok(Res::False);
                 }
             }
@@ -0,0 +1,60 @@
+use crate::constants::ANY_OPERATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnyOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for AnyOperator {
+    fn get_prefix(&self) -> String {
+        ANY_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for AnyOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::BoolPrimitive(_))
+            | Item::Map(_)
+            | Item::Vector(_)
+            | Item::Modifier(_) => {
+                self.values.push((k, v));
+                Ok(true)
+            }
+            _ => Err(DBError::new(
+                "Any operator can contain only find expressions",
+            )),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::AnyOperator(self))
+    }
+}
+
+impl AnyOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &Item)> {
+        let mut ve: Vec<(&Primitive, &Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v));
+        }
+        ve
+    }
+}
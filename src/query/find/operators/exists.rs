@@ -0,0 +1,59 @@
+use crate::constants::EXISTS_OPERATOR;
+use crate::data_types::primitives::bool::BoolPrimitive;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExistsOperator {
+    values: Vec<(Primitive, BoolPrimitive)>,
+}
+
+impl BaseTySONItemInterface for ExistsOperator {
+    fn get_prefix(&self) -> String {
+        EXISTS_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for ExistsOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::BoolPrimitive(o)) => {
+                self.values.push((k, o));
+                Ok(true)
+            }
+            _ => Err(DBError::new("Exists operator can contain only bools")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((
+                k.clone(),
+                Item::Primitive(Primitive::BoolPrimitive(v.clone())),
+            ));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::ExistsOperator(self))
+    }
+}
+
+impl ExistsOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, bool)> {
+        let mut ve: Vec<(&Primitive, bool)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v.val()));
+        }
+        ve
+    }
+}
@@ -0,0 +1,55 @@
+use crate::constants::IEQ_OPERATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IeqOperator {
+    values: Vec<(Primitive, StringPrimitive)>,
+}
+
+impl BaseTySONItemInterface for IeqOperator {
+    fn get_prefix(&self) -> String {
+        IEQ_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for IeqOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::StringPrimitive(o)) => {
+                self.values.push((k, o));
+                Ok(true)
+            }
+            _ => Err(DBError::new("Ieq operator can contain only strings")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), Item::Primitive(Primitive::from(v.clone()))));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::IeqOperator(self))
+    }
+}
+
+impl IeqOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &str)> {
+        let mut ve: Vec<(&Primitive, &str)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v.get_value()));
+        }
+        ve
+    }
+}
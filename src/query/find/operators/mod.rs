@@ -1,9 +1,20 @@
 pub mod and;
+pub mod any;
+pub mod contains;
 pub mod eq;
+pub mod exists;
 pub mod gt;
 pub mod gte;
+pub mod ieq;
+pub mod r#in;
 pub mod lt;
 pub mod lte;
 pub(crate) mod neq;
+pub mod nin;
 pub mod not;
 pub mod or;
+pub mod regex;
+pub mod size;
+pub mod starts_with;
+pub mod r#type;
+pub mod via;
@@ -0,0 +1,68 @@
+use crate::constants::NIN_OPERATOR;
+use crate::data_types::vector::storage::StorageVector;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap, TySONVector, VectorItem};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NinOperator {
+    values: Vec<(Primitive, Vec<Primitive>)>,
+}
+
+impl BaseTySONItemInterface for NinOperator {
+    fn get_prefix(&self) -> String {
+        NIN_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for NinOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Vector(VectorItem::StorageVector(o)) => {
+                let mut options: Vec<Primitive> = vec![];
+                for i in o.get_items() {
+                    match i {
+                        Item::Primitive(pr) => options.push(pr.clone()),
+                        _ => {
+                            return Err(DBError::new(
+                                "Nin operator vector can contain only primitives",
+                            ))
+                        }
+                    }
+                }
+                self.values.push((k, options));
+                Ok(true)
+            }
+            _ => Err(DBError::new("Nin operator can contain only vectors")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            let items = v.iter().map(|i| Item::Primitive(i.clone())).collect();
+            ve.push((k.clone(), StorageVector { items }.to_item()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::NinOperator(self))
+    }
+}
+
+impl NinOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &Vec<Primitive>)> {
+        let mut ve: Vec<(&Primitive, &Vec<Primitive>)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v));
+        }
+        ve
+    }
+}
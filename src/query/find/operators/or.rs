@@ -41,6 +41,39 @@ impl TySONVector for OrOperator {
             Item::Map(MapItem::LteOperator(_)) => {
                 self.items.push(item);
             }
+            Item::Map(MapItem::InOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::NinOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::ExistsOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::TypeOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::StartsWithOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::ContainsOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::IeqOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::RegexOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::AnyOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::SizeOperator(_)) => {
+                self.items.push(item);
+            }
+            Item::Map(MapItem::ViaOperator(_)) => {
+                self.items.push(item);
+            }
             _ => return Err(DBError::new("Unsupported item for OR operator")),
         };
         Ok(true)
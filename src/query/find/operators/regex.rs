@@ -0,0 +1,83 @@
+use regex::{Regex, RegexBuilder};
+
+use crate::constants::{REGEX_OPERATOR, REGEX_PATTERN_LENGTH_LIMIT, REGEX_SIZE_LIMIT};
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap};
+
+#[derive(Clone, Debug)]
+pub struct RegexOperator {
+    values: Vec<(Primitive, StringPrimitive)>,
+    compiled: Vec<Regex>,
+}
+
+impl BaseTySONItemInterface for RegexOperator {
+    fn get_prefix(&self) -> String {
+        REGEX_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for RegexOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            values: vec![],
+            compiled: vec![],
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::StringPrimitive(o)) => {
+                self.compiled.push(compile(o.get_value())?);
+                self.values.push((k, o));
+                Ok(true)
+            }
+            _ => Err(DBError::new("Regex operator can contain only strings")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), Item::Primitive(Primitive::from(v.clone()))));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::RegexOperator(self))
+    }
+}
+
+impl PartialEq for RegexOperator {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl Eq for RegexOperator {}
+
+impl RegexOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &Regex)> {
+        let mut ve: Vec<(&Primitive, &Regex)> = vec![];
+        for ((k, _), r) in self.values.iter().zip(&self.compiled) {
+            ve.push((k, r));
+        }
+        ve
+    }
+}
+
+// The regex engine matches in linear time, so only the pattern and the compiled
+// program size have to be limited to keep the server loop responsive
+fn compile(pattern: &str) -> Result<Regex, DBError> {
+    if pattern.len() > REGEX_PATTERN_LENGTH_LIMIT {
+        return Err(DBError::new("Regex pattern is too long"));
+    }
+    RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .dfa_size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|e| DBError::new(format!("Invalid regex pattern: {}", e).as_str()))
+}
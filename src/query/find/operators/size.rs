@@ -0,0 +1,63 @@
+use crate::constants::SIZE_OPERATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SizeOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for SizeOperator {
+    fn get_prefix(&self) -> String {
+        SIZE_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for SizeOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::NumberPrimitive(_))
+            | Item::Map(MapItem::EqOperator(_))
+            | Item::Map(MapItem::NeqOperator(_))
+            | Item::Map(MapItem::GtOperator(_))
+            | Item::Map(MapItem::GteOperator(_))
+            | Item::Map(MapItem::LtOperator(_))
+            | Item::Map(MapItem::LteOperator(_)) => {
+                self.values.push((k, v));
+                Ok(true)
+            }
+            _ => Err(DBError::new(
+                "Size operator can contain only numbers or comparison operators",
+            )),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::SizeOperator(self))
+    }
+}
+
+impl SizeOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &Item)> {
+        let mut ve: Vec<(&Primitive, &Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v));
+        }
+        ve
+    }
+}
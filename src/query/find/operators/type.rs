@@ -0,0 +1,57 @@
+use crate::constants::TYPE_OPERATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap, TySONPrimitive};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeOperator {
+    values: Vec<(Primitive, StringPrimitive)>,
+}
+
+impl BaseTySONItemInterface for TypeOperator {
+    fn get_prefix(&self) -> String {
+        TYPE_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for TypeOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match v {
+            Item::Primitive(Primitive::StringPrimitive(o)) => {
+                self.values.push((k, o));
+                Ok(true)
+            }
+            _ => Err(DBError::new(
+                "Type operator can contain only strings with type prefixes",
+            )),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), Item::Primitive(Primitive::from(v.clone()))));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::TypeOperator(self))
+    }
+}
+
+impl TypeOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, String)> {
+        let mut ve: Vec<(&Primitive, String)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v.get_string_value()));
+        }
+        ve
+    }
+}
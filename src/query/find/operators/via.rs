@@ -0,0 +1,92 @@
+use crate::constants::{DEPTH_OPTION, FETCH_DEPTH_LIMIT, VIA_OPERATOR};
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ViaOperator {
+    values: Vec<(Primitive, Item)>,
+    depth: Option<NumberPrimitive>,
+}
+
+impl BaseTySONItemInterface for ViaOperator {
+    fn get_prefix(&self) -> String {
+        VIA_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for ViaOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            values: vec![],
+            depth: None,
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match (&k, v) {
+            (Primitive::StringPrimitive(o), Item::Primitive(Primitive::NumberPrimitive(n)))
+                if o.get_value() == DEPTH_OPTION =>
+            {
+                if n.get_value() < 1.0 || n.get_value() > FETCH_DEPTH_LIMIT as f64 {
+                    return Err(DBError::new("Via depth is out of range"));
+                }
+                self.depth = Some(n);
+                Ok(true)
+            }
+            (Primitive::StringPrimitive(_), _) => Err(DBError::new(
+                "Via operator supports only numeric depth option",
+            )),
+            (_, v) => match v {
+                Item::Primitive(Primitive::BoolPrimitive(_))
+                | Item::Map(_)
+                | Item::Vector(_)
+                | Item::Modifier(_) => {
+                    self.values.push((k, v));
+                    Ok(true)
+                }
+                _ => Err(DBError::new(
+                    "Via operator can contain only find expressions",
+                )),
+            },
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        if let Some(depth) = &self.depth {
+            ve.push((
+                Primitive::from(StringPrimitive::from(DEPTH_OPTION)),
+                Item::Primitive(Primitive::NumberPrimitive(depth.clone())),
+            ));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::ViaOperator(self))
+    }
+}
+
+impl ViaOperator {
+    pub fn get_values(&self) -> Vec<(&Primitive, &Item)> {
+        let mut ve: Vec<(&Primitive, &Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k, v));
+        }
+        ve
+    }
+
+    pub fn get_depth(&self) -> usize {
+        match &self.depth {
+            Some(n) => n.get_value() as usize,
+            None => 1,
+        }
+    }
+}
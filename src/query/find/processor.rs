@@ -6,7 +6,7 @@ use crate::response::{QueryResponse, QueryStatus};
 use crate::storage::buffer::{FilterBuffer, InsertBuffer};
 use crate::{DBError, Item, Link, Primitive, Storage};
 
-fn get_ids_list(
+pub(crate) fn get_ids_list(
     storage: &Storage,
     collection_name: String,
     insert_buf: &InsertBuffer,
@@ -39,11 +39,23 @@ impl FindQuery {
         vec![
             QueryOperation::FindOperation,
             QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
+            QueryOperation::UpsertOperation,
             QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::GroupOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
             QueryOperation::SortOperation,
             QueryOperation::LimitOperation,
             QueryOperation::OffsetOperation,
             QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+            QueryOperation::ShortestPathOperation,
         ]
     }
 }
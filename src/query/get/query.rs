@@ -22,7 +22,15 @@ impl TySONVector for GetQuery {
 
     fn push(&mut self, item: Item) -> Result<bool, DBError> {
         match &item {
-            Item::Primitive(Primitive::Link(_)) => self.items.push(item),
+            Item::Primitive(Primitive::Link(_))
+            | Item::Primitive(Primitive::StepResult(_))
+            | Item::Primitive(Primitive::Param(_)) => self.items.push(item),
+            // A step result can be resolved to a vector of ids
+            Item::Vector(VectorItem::StorageVector(o)) => {
+                for i in o.get_items() {
+                    self.push(i.clone())?;
+                }
+            }
             _ => {
                 return Err(DBError::new("Get query can contain only links"));
             }
@@ -56,10 +64,21 @@ impl GetQuery {
     pub fn next_available(&self) -> Vec<QueryOperation> {
         vec![
             QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
             QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::GroupOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
             QueryOperation::LimitOperation,
             QueryOperation::OffsetOperation,
             QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+            QueryOperation::ShortestPathOperation,
         ]
     }
 }
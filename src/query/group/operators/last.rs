@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+use crate::constants::LAST_ACCUMULATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LastAccumulator {
+    expr: Box<Item>,
+}
+
+impl BaseTySONItemInterface for LastAccumulator {
+    fn get_prefix(&self) -> String {
+        LAST_ACCUMULATOR.to_string()
+    }
+}
+
+impl TySONModifier for LastAccumulator {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(Primitive::PathToValue(_))
+            | Item::Primitive(Primitive::RootPrimitive(_)) => Ok(Self {
+                expr: Box::new(value),
+            }),
+            _ => Err(DBError::new(
+                "Last accumulator can contain only paths or root",
+            )),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.expr.serialize()
+    }
+}
+
+impl LastAccumulator {
+    pub fn get_value(&self) -> &Item {
+        self.expr.as_ref()
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+use crate::constants::MIN_ACCUMULATOR;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MinAccumulator {
+    expr: Box<Item>,
+}
+
+impl BaseTySONItemInterface for MinAccumulator {
+    fn get_prefix(&self) -> String {
+        MIN_ACCUMULATOR.to_string()
+    }
+}
+
+impl TySONModifier for MinAccumulator {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(Primitive::PathToValue(_))
+            | Item::Primitive(Primitive::RootPrimitive(_)) => Ok(Self {
+                expr: Box::new(value),
+            }),
+            _ => Err(DBError::new(
+                "Min accumulator can contain only paths or root",
+            )),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.expr.serialize()
+    }
+}
+
+impl MinAccumulator {
+    pub fn get_value(&self) -> &Item {
+        self.expr.as_ref()
+    }
+}
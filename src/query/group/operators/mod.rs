@@ -0,0 +1,8 @@
+pub mod avg;
+pub mod count;
+pub mod first;
+pub mod last;
+pub mod max;
+pub mod min;
+pub mod push;
+pub mod sum;
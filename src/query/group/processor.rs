@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use crate::constants::{NULL, ROOT, STORAGE_MAP, STORAGE_VECTOR};
+use crate::data_types::modifier::ModifierItem;
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::query::group::query::GroupQuery;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{
+    DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap, TySONVector,
+    VectorItem,
+};
+
+enum Accumulator {
+    Count(usize),
+    Sum(f64),
+    Avg(f64, usize),
+    Min(Option<Primitive>),
+    Max(Option<Primitive>),
+    Push(Vec<Item>),
+    First(Option<Item>),
+    Last(Option<Item>),
+}
+
+/// Primitives of different types are ordered by the type, not by the value
+fn check_same_type(current: &Option<Primitive>, value: &Primitive) -> Result<(), DBError> {
+    match current {
+        Some(pr) if pr.get_prefix() != value.get_prefix() => Err(DBError::new(
+            "Min and max accumulators support values of the same type",
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl Accumulator {
+    fn new(item: &Item) -> Result<Self, DBError> {
+        match item {
+            Item::Primitive(Primitive::CountAccumulator(_)) => Ok(Accumulator::Count(0)),
+            Item::Modifier(ModifierItem::SumAccumulator(_)) => Ok(Accumulator::Sum(0.0)),
+            Item::Modifier(ModifierItem::AvgAccumulator(_)) => Ok(Accumulator::Avg(0.0, 0)),
+            Item::Modifier(ModifierItem::MinAccumulator(_)) => Ok(Accumulator::Min(None)),
+            Item::Modifier(ModifierItem::MaxAccumulator(_)) => Ok(Accumulator::Max(None)),
+            Item::Modifier(ModifierItem::PushAccumulator(_)) => Ok(Accumulator::Push(vec![])),
+            Item::Modifier(ModifierItem::FirstAccumulator(_)) => Ok(Accumulator::First(None)),
+            Item::Modifier(ModifierItem::LastAccumulator(_)) => Ok(Accumulator::Last(None)),
+            _ => Err(DBError::new("Unexpected group accumulator")),
+        }
+    }
+
+    fn add(&mut self, values: Vec<Item>) -> Result<(), DBError> {
+        match self {
+            Accumulator::Count(n) => *n += 1,
+            Accumulator::Sum(sum) => {
+                for v in values {
+                    if let Item::Primitive(Primitive::NumberPrimitive(o)) = v {
+                        *sum += o.get_value();
+                    }
+                }
+            }
+            Accumulator::Avg(sum, n) => {
+                for v in values {
+                    if let Item::Primitive(Primitive::NumberPrimitive(o)) = v {
+                        *sum += o.get_value();
+                        *n += 1;
+                    }
+                }
+            }
+            Accumulator::Min(min) => {
+                for v in values {
+                    match v {
+                        Item::Primitive(Primitive::NullPrimitive(_)) => {}
+                        Item::Primitive(pr) => {
+                            check_same_type(min, &pr)?;
+                            if min.is_none() || Some(&pr) < min.as_ref() {
+                                *min = Some(pr);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Accumulator::Max(max) => {
+                for v in values {
+                    match v {
+                        Item::Primitive(Primitive::NullPrimitive(_)) => {}
+                        Item::Primitive(pr) => {
+                            check_same_type(max, &pr)?;
+                            if max.is_none() || Some(&pr) > max.as_ref() {
+                                *max = Some(pr);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Accumulator::Push(items) => items.extend(values),
+            Accumulator::First(first) => {
+                if first.is_none() {
+                    *first = Some(to_single(values)?);
+                }
+            }
+            Accumulator::Last(last) => *last = Some(to_single(values)?),
+        }
+        Ok(())
+    }
+
+    fn to_item(&self) -> Result<Item, DBError> {
+        let null = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+        let res = match self {
+            Accumulator::Count(n) => {
+                Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(*n)))
+            }
+            Accumulator::Sum(sum) => {
+                Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(*sum)))
+            }
+            Accumulator::Avg(sum, n) => {
+                if *n == 0 {
+                    null
+                } else {
+                    Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(
+                        *sum / *n as f64,
+                    )))
+                }
+            }
+            Accumulator::Min(v) | Accumulator::Max(v) => match v {
+                Some(pr) => Item::from(pr.clone()),
+                None => null,
+            },
+            Accumulator::Push(items) => {
+                let mut v = VectorItem::new(STORAGE_VECTOR.to_string())?;
+                for i in items {
+                    v.push(i.clone())?;
+                }
+                Item::from(v)
+            }
+            Accumulator::First(v) | Accumulator::Last(v) => match v {
+                Some(i) => i.clone(),
+                None => null,
+            },
+        };
+        Ok(res)
+    }
+}
+
+fn to_single(values: Vec<Item>) -> Result<Item, DBError> {
+    match values.into_iter().next() {
+        Some(v) => Ok(v),
+        None => Ok(Item::Primitive(Primitive::new(
+            NULL.to_string(),
+            "".to_string(),
+        )?)),
+    }
+}
+
+fn get_path(accumulator: &Item) -> Option<&Item> {
+    match accumulator {
+        Item::Modifier(o) => Some(o.get_value()),
+        _ => None,
+    }
+}
+
+fn get_values(
+    storage: &Storage,
+    path: &Item,
+    id: &Link,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<Item>, DBError> {
+    match path {
+        Item::Primitive(Primitive::PathToValue(p)) => {
+            let mut res: Vec<Item> = vec![];
+            for v in storage.get_values_by_path(p, id, insert_buf)? {
+                res.push(storage.fetch(&v, insert_buf)?);
+            }
+            Ok(res)
+        }
+        Item::Primitive(Primitive::RootPrimitive(_)) => Ok(vec![storage.get_item_by_link(
+            id,
+            insert_buf,
+            storage.fetch_depth,
+            None,
+        )?]),
+        _ => Ok(vec![]),
+    }
+}
+
+pub fn group(
+    storage: &Storage,
+    query: &GroupQuery,
+    buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let key_path = match &query.key {
+        Some(Primitive::NullPrimitive(_)) | None => None,
+        Some(pr) => Some(Item::from(pr.clone())),
+    };
+    let key_name = match &query.key {
+        Some(Primitive::PathToValue(p)) => p.value.clone(),
+        _ => ROOT.to_string(),
+    };
+
+    let mut groups: Vec<(Item, Vec<Accumulator>)> = vec![];
+    // primitive keys are looked up by their serialized value,
+    // containers are compared one by one
+    let mut primitive_groups: HashMap<String, usize> = HashMap::new();
+    for id in &buf.ids {
+        let key = match &key_path {
+            Some(path) => to_single(get_values(storage, path, id, insert_buf)?)?,
+            None => Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?),
+        };
+        let position = match &key {
+            Item::Primitive(pr) => primitive_groups.get(&pr.serialize()).copied(),
+            _ => groups.iter().position(|(k, _)| *k == key),
+        };
+        let position = match position {
+            Some(p) => p,
+            None => {
+                let mut accumulators: Vec<Accumulator> = vec![];
+                for (_, item) in &query.accumulators {
+                    accumulators.push(Accumulator::new(item)?);
+                }
+                if let Item::Primitive(pr) = &key {
+                    primitive_groups.insert(pr.serialize(), groups.len());
+                }
+                groups.push((key, accumulators));
+                groups.len() - 1
+            }
+        };
+        for (index, (_, item)) in query.accumulators.iter().enumerate() {
+            let values = match get_path(item) {
+                Some(path) => get_values(storage, path, id, insert_buf)?,
+                None => vec![],
+            };
+            groups[position].1[index].add(values)?;
+        }
+    }
+
+    let mut data = VectorItem::new(STORAGE_VECTOR.to_string())?;
+    for (key, accumulators) in &groups {
+        let mut result = MapItem::new(STORAGE_MAP.to_string())?;
+        if key_path.is_some() {
+            result.insert(
+                Primitive::from(StringPrimitive::from(key_name.as_str())),
+                key.clone(),
+            )?;
+        }
+        for (index, (name, _)) in query.accumulators.iter().enumerate() {
+            result.insert(name.clone(), accumulators[index].to_item()?)?;
+        }
+        data.push(Item::Map(result))?;
+    }
+    let meta = Meta::FindMeta(FindMeta::new(groups.len()));
+    Ok(QueryResponse::new(
+        Item::from(data),
+        meta,
+        QueryStatus::Ready,
+    ))
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::constants::GROUP_QUERY;
+use crate::data_types::map::storage::StorageMap;
+use crate::data_types::modifier::ModifierItem;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupQuery {
+    pub(crate) key: Option<Primitive>,
+    pub(crate) accumulators: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for GroupQuery {
+    fn get_prefix(&self) -> String {
+        GROUP_QUERY.to_string()
+    }
+}
+
+impl TySONMap for GroupQuery {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            key: None,
+            accumulators: vec![],
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        if self.key.is_some() {
+            return Err(DBError::new("Group query can contain only one key"));
+        }
+        match &k {
+            Primitive::PathToValue(o) => {
+                if o.is_wildcard() {
+                    return Err(DBError::new("Wildcard paths can not be group keys"));
+                }
+            }
+            Primitive::RootPrimitive(_) | Primitive::NullPrimitive(_) => {}
+            _ => return Err(DBError::new("Group key can be only a path, root or null")),
+        }
+        match v {
+            Item::Map(MapItem::StorageMap(o)) => {
+                for (name, accumulator) in o.get_items() {
+                    match accumulator {
+                        Item::Primitive(Primitive::CountAccumulator(_))
+                        | Item::Modifier(ModifierItem::SumAccumulator(_))
+                        | Item::Modifier(ModifierItem::AvgAccumulator(_))
+                        | Item::Modifier(ModifierItem::MinAccumulator(_))
+                        | Item::Modifier(ModifierItem::MaxAccumulator(_))
+                        | Item::Modifier(ModifierItem::PushAccumulator(_))
+                        | Item::Modifier(ModifierItem::FirstAccumulator(_))
+                        | Item::Modifier(ModifierItem::LastAccumulator(_)) => {
+                            self.accumulators.push((name, accumulator));
+                        }
+                        _ => return Err(DBError::new("Group query can contain only accumulators")),
+                    }
+                }
+            }
+            _ => return Err(DBError::new("Group accumulators must be a map")),
+        }
+        self.key = Some(k);
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut accumulators = StorageMap {
+            values: HashMap::new(),
+        };
+        for (k, v) in &self.accumulators {
+            if let Primitive::StringPrimitive(name) = k {
+                accumulators.values.insert(name.clone(), v.clone());
+            }
+        }
+        match &self.key {
+            Some(key) => vec![(key.clone(), accumulators.to_item())],
+            None => vec![],
+        }
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::GroupQuery(self))
+    }
+}
+
+impl GroupQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+}
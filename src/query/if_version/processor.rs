@@ -0,0 +1,35 @@
+use crate::constants::NULL;
+use crate::query::if_version::query::IfVersionQuery;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Primitive, Storage, TySONPrimitive};
+
+/// Aborts the transaction if any of the found objects has another version
+pub fn if_version(
+    storage: &Storage,
+    query: &IfVersionQuery,
+    filter_buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let expected = match query.get_value() {
+        Item::Primitive(Primitive::NumberPrimitive(n)) => n.get_value(),
+        _ => return Err(DBError::new("If version query can contain only a number")),
+    };
+    for id in &filter_buf.ids {
+        let version = storage.get_version(id, insert_buf);
+        if version as f64 != expected {
+            return Err(DBError::new(
+                format!(
+                    "Version mismatch: object {} has version {}",
+                    id.get_string_value(),
+                    version
+                )
+                .as_str(),
+            ));
+        }
+    }
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(filter_buf.ids.len()));
+    Ok(QueryResponse::new(data, meta, QueryStatus::NotFetched))
+}
@@ -0,0 +1,55 @@
+use std::fmt::Debug;
+
+use crate::constants::IF_VERSION_QUERY;
+use crate::data_types::modifier::ModifierItem;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IfVersionQuery {
+    value: Box<Item>,
+}
+
+impl BaseTySONItemInterface for IfVersionQuery {
+    fn get_prefix(&self) -> String {
+        IF_VERSION_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for IfVersionQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(Primitive::NumberPrimitive(_)) => Ok(Self {
+                value: Box::new(value),
+            }),
+            _ => Err(DBError::new("If version query can contain only a number")),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.value.serialize()
+    }
+}
+
+impl IfVersionQuery {
+    pub fn get_value(&self) -> &Item {
+        self.value.as_ref()
+    }
+
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![
+            QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::DeleteOperation,
+        ]
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Modifier(ModifierItem::IfVersionQuery(self))
+    }
+}
@@ -1,19 +1,55 @@
 use crate::constants::RESPONSE_IDS;
+use crate::query::insert::query::{InsertWithIdsQuery, OnConflict};
 use crate::response::ids::ResponseIds;
 use crate::response::meta::{InsertMeta, Meta};
 use crate::response::{QueryResponse, QueryStatus};
 use crate::storage::buffer::InsertBuffer;
+use crate::tyson::primitive::TySONPrimitive;
 use crate::{DBError, Item, Storage, TySONVector, VectorItem};
 
 pub fn insert(
     storage: &Storage,
     collection_name: String,
     items: &Vec<Item>,
-    mut buf: &mut InsertBuffer,
+    buf: &mut InsertBuffer,
 ) -> Result<QueryResponse, DBError> {
     let mut links: ResponseIds = ResponseIds::new(RESPONSE_IDS.to_string())?;
     for item in items {
-        links.push(storage.insert_item(collection_name.clone(), &mut buf, item.clone())?)?;
+        links.push(storage.insert_item(collection_name.clone(), buf, item.clone())?)?;
+    }
+    let meta = InsertMeta::new(links.items.len());
+    Ok(QueryResponse::new(
+        Item::Vector(VectorItem::ResponseIds(links)),
+        Meta::InsertMeta(meta),
+        QueryStatus::Ready,
+    ))
+}
+
+pub fn insert_with_ids(
+    storage: &Storage,
+    collection_name: String,
+    query: &InsertWithIdsQuery,
+    buf: &mut InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let mut links: ResponseIds = ResponseIds::new(RESPONSE_IDS.to_string())?;
+    for (link, item) in &query.items {
+        if link.collection_name != collection_name {
+            return Err(DBError::new(
+                "Inserted ids must belong to the queried collection",
+            ));
+        }
+        if storage.exists(link, buf) {
+            match query.on_conflict {
+                OnConflict::Error => {
+                    return Err(DBError::new(
+                        format!("Object with id {} already exists", link.get_string_value())
+                            .as_str(),
+                    ))
+                }
+                OnConflict::Skip => continue,
+            }
+        }
+        links.push(storage.insert_item_by_link(link.clone(), buf, item.clone())?)?;
     }
     let meta = InsertMeta::new(links.items.len());
     Ok(QueryResponse::new(
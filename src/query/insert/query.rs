@@ -1,6 +1,8 @@
-use crate::constants::INSERT_QUERY;
+use crate::constants::{ERROR_ON_CONFLICT, INSERT_QUERY, ON_CONFLICT_OPTION, SKIP_ON_CONFLICT};
 use crate::query::operations::QueryOperation;
-use crate::{DBError, Item, TySONVector, VectorItem};
+use crate::{
+    DBError, Item, Link, MapItem, Primitive, StringPrimitive, TySONMap, TySONVector, VectorItem,
+};
 
 use crate::tyson::item::BaseTySONItemInterface;
 
@@ -53,3 +55,98 @@ impl InsertQuery {
     //     Ok(Response { data })
     // }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OnConflict {
+    Error,
+    Skip,
+}
+
+impl OnConflict {
+    fn from_str(value: &str) -> Result<Self, DBError> {
+        match value {
+            ERROR_ON_CONFLICT => Ok(OnConflict::Error),
+            SKIP_ON_CONFLICT => Ok(OnConflict::Skip),
+            _ => Err(DBError::new("Unknown on conflict policy")),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            OnConflict::Error => ERROR_ON_CONFLICT,
+            OnConflict::Skip => SKIP_ON_CONFLICT,
+        }
+    }
+}
+
+/// Insert with the ids provided by the caller: `insert{users|<uuid>|: m{...}}`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsertWithIdsQuery {
+    pub(crate) items: Vec<(Link, Item)>,
+    pub(crate) on_conflict: OnConflict,
+}
+
+impl BaseTySONItemInterface for InsertWithIdsQuery {
+    fn get_prefix(&self) -> String {
+        INSERT_QUERY.to_string()
+    }
+}
+
+impl TySONMap for InsertWithIdsQuery {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            items: vec![],
+            on_conflict: OnConflict::Error,
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match (k, v) {
+            (
+                Primitive::StringPrimitive(o),
+                Item::Primitive(Primitive::StringPrimitive(policy)),
+            ) if o.get_value() == ON_CONFLICT_OPTION => {
+                self.on_conflict = OnConflict::from_str(policy.get_value())?;
+                Ok(true)
+            }
+            (Primitive::StringPrimitive(_), _) => Err(DBError::new(
+                "Insert query supports only string on_conflict option",
+            )),
+            (Primitive::Link(link), v) => {
+                if self.items.iter().any(|(l, _)| *l == link) {
+                    return Err(DBError::new("Insert query contains duplicated ids"));
+                }
+                self.items.push((link, v));
+                Ok(true)
+            }
+            _ => Err(DBError::new("Insert query keys must be ids")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.items {
+            ve.push((Primitive::from(k.clone()), v.clone()));
+        }
+        ve.push((
+            Primitive::from(StringPrimitive::from(ON_CONFLICT_OPTION)),
+            Item::from(Primitive::from(StringPrimitive::from(
+                self.on_conflict.as_str(),
+            ))),
+        ));
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::InsertWithIdsQuery(self))
+    }
+}
+
+impl InsertWithIdsQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+}
@@ -21,7 +21,10 @@ impl TySONModifier for LimitQuery {
         Self: Sized,
     {
         match &value {
-            Item::Primitive(Primitive::NumberPrimitive(_pr)) => Ok(Self {
+            // Placeholders are replaced with numbers before the query runs
+            Item::Primitive(Primitive::NumberPrimitive(_))
+            | Item::Primitive(Primitive::Param(_))
+            | Item::Primitive(Primitive::StepResult(_)) => Ok(Self {
                 expr: Box::new(value),
             }),
             _ => Err(DBError::new("Limit supports only numbers as a parameter")),
@@ -42,10 +45,20 @@ impl LimitQuery {
         vec![
             QueryOperation::FindOperation,
             QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
             QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
             QueryOperation::LimitOperation,
             QueryOperation::OffsetOperation,
             QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+            QueryOperation::ShortestPathOperation,
         ]
     }
 }
@@ -1,11 +1,23 @@
+pub mod assert;
+pub mod count;
+pub mod cursor;
 pub mod delete;
+pub mod depth;
+pub mod distinct;
 pub mod find;
 pub mod get;
+pub mod group;
+pub mod if_version;
 pub mod insert;
 pub mod limit;
 pub mod offset;
+pub mod on_delete;
 pub mod operations;
 pub mod project;
 pub mod queryset;
+pub mod referenced_by;
+pub mod replace;
+pub mod shortest_path;
 pub mod sort;
+pub mod traverse;
 pub mod update;
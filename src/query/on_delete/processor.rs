@@ -0,0 +1,174 @@
+use crate::constants::{
+    DELETED, NULL, PATH_SEPARATOR, POLICIES_COLLECTION_NAME, ROOT, STORAGE_MAP, WILDCARD_PATH,
+};
+use crate::query::on_delete::query::{DeletePolicy, OnDeleteQuery};
+use crate::response::meta::{Meta, UpdateMeta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::InsertBuffer;
+use crate::{DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap};
+
+const COLLECTION_FIELD: &str = "collection";
+const PATH_FIELD: &str = "path";
+const POLICY_FIELD: &str = "policy";
+
+#[derive(Debug)]
+pub(crate) struct PolicyRecord {
+    id: Link,
+    collection_name: String,
+    path: String,
+    pub(crate) policy: DeletePolicy,
+}
+
+fn get_field(record: &MapItem, field: &str) -> Result<String, DBError> {
+    match record {
+        MapItem::StorageMap(o) => match o.get_by_str(field)? {
+            Some(Item::Primitive(Primitive::StringPrimitive(v))) => Ok(v.get_value().to_string()),
+            _ => Err(DBError::new("Internal error: broken delete policy")),
+        },
+        _ => Err(DBError::new("Internal error: broken delete policy")),
+    }
+}
+
+fn to_record(id: &Link, item: &Item) -> Result<Option<PolicyRecord>, DBError> {
+    match item {
+        Item::Map(o) => Ok(Some(PolicyRecord {
+            id: id.clone(),
+            collection_name: get_field(o, COLLECTION_FIELD)?,
+            path: get_field(o, PATH_FIELD)?,
+            policy: DeletePolicy::from_str(get_field(o, POLICY_FIELD)?.as_str())?,
+        })),
+        _ => Ok(None),
+    }
+}
+
+fn get_records(storage: &Storage, insert_buf: &InsertBuffer) -> Result<Vec<PolicyRecord>, DBError> {
+    let mut res: Vec<PolicyRecord> = vec![];
+    if let Some(collection) = storage.get_collection(POLICIES_COLLECTION_NAME.to_string()) {
+        for (id, item) in &collection.values {
+            if !insert_buf.items.contains_key(id) {
+                if let Some(record) = to_record(id, item)? {
+                    res.push(record);
+                }
+            }
+        }
+    }
+    for (id, item) in &insert_buf.items {
+        if id.collection_name == POLICIES_COLLECTION_NAME {
+            if let Some(record) = to_record(id, item)? {
+                res.push(record);
+            }
+        }
+    }
+    Ok(res)
+}
+
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern_parts: Vec<&str> = pattern.split(PATH_SEPARATOR).collect();
+    let path_parts: Vec<&str> = path.split(PATH_SEPARATOR).collect();
+    pattern_parts.len() == path_parts.len()
+        && pattern_parts
+            .iter()
+            .zip(path_parts.iter())
+            .all(|(p, v)| *p == WILDCARD_PATH || p == v)
+}
+
+/// Checks if the first matching pattern is more specific than the second one.
+/// The patterns are compared segment by segment - the first exact segment against a `*` wins.
+fn is_more_specific(pattern: &str, other: &str) -> bool {
+    for (p, o) in pattern
+        .split(PATH_SEPARATOR)
+        .zip(other.split(PATH_SEPARATOR))
+    {
+        match (p == WILDCARD_PATH, o == WILDCARD_PATH) {
+            (false, true) => return true,
+            (true, false) => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Finds the policy for a link stored by the path in the collection documents.
+/// Path policies take precedence over the collection-wide `root` one.
+/// If several path policies match, the most specific one is used.
+pub(crate) fn get_delete_policy(
+    storage: &Storage,
+    collection_name: &str,
+    path: &str,
+    insert_buf: &InsertBuffer,
+) -> Result<Option<DeletePolicy>, DBError> {
+    let mut collection_policy: Option<DeletePolicy> = None;
+    let mut path_policy: Option<PolicyRecord> = None;
+    for record in get_records(storage, insert_buf)? {
+        if record.collection_name != collection_name {
+            continue;
+        }
+        if record.path == ROOT {
+            if path == ROOT {
+                return Ok(Some(record.policy));
+            }
+            collection_policy = Some(record.policy);
+        } else if path_matches(record.path.as_str(), path) {
+            let replace = match &path_policy {
+                Some(current) => is_more_specific(record.path.as_str(), current.path.as_str()),
+                None => true,
+            };
+            if replace {
+                path_policy = Some(record);
+            }
+        }
+    }
+    Ok(path_policy.map(|r| r.policy).or(collection_policy))
+}
+
+pub fn on_delete(
+    storage: &Storage,
+    collection_name: String,
+    query: &OnDeleteQuery,
+    insert_buf: &mut InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let records = get_records(storage, insert_buf)?;
+    for (k, v) in query.get_items() {
+        let path = match &k {
+            Primitive::PathToValue(o) => o.value.clone(),
+            _ => ROOT.to_string(),
+        };
+        let existing = records
+            .iter()
+            .find(|r| r.collection_name == collection_name && r.path == path)
+            .map(|r| r.id.clone());
+        match v {
+            Item::Primitive(Primitive::StringPrimitive(policy)) => {
+                let mut record = MapItem::new(STORAGE_MAP.to_string())?;
+                record.insert(
+                    Primitive::from(StringPrimitive::from(COLLECTION_FIELD)),
+                    Item::from(Primitive::from(StringPrimitive::from(
+                        collection_name.as_str(),
+                    ))),
+                )?;
+                record.insert(
+                    Primitive::from(StringPrimitive::from(PATH_FIELD)),
+                    Item::from(Primitive::from(StringPrimitive::from(path.as_str()))),
+                )?;
+                record.insert(
+                    Primitive::from(StringPrimitive::from(POLICY_FIELD)),
+                    Item::from(Primitive::from(policy)),
+                )?;
+                let id =
+                    existing.unwrap_or_else(|| Link::create(POLICIES_COLLECTION_NAME.to_string()));
+                insert_buf.insert(id, Item::Map(record));
+            }
+            _ => {
+                if let Some(id) = existing {
+                    insert_buf.insert(
+                        id,
+                        Item::Primitive(Primitive::new(DELETED.to_string(), "".to_string())?),
+                    );
+                }
+            }
+        }
+    }
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::UpdateMeta(UpdateMeta::new(query.values.len()));
+    Ok(QueryResponse::new(data, meta, QueryStatus::Ready))
+}
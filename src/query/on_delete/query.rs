@@ -0,0 +1,82 @@
+use crate::constants::{CASCADE_POLICY, ON_DELETE_QUERY, RESTRICT_POLICY, SET_NULL_POLICY};
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeletePolicy {
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
+impl DeletePolicy {
+    pub(crate) fn from_str(value: &str) -> Result<Self, DBError> {
+        match value {
+            RESTRICT_POLICY => Ok(DeletePolicy::Restrict),
+            CASCADE_POLICY => Ok(DeletePolicy::Cascade),
+            SET_NULL_POLICY => Ok(DeletePolicy::SetNull),
+            _ => Err(DBError::new(
+                format!("Unknown delete policy: {}", value).as_str(),
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OnDeleteQuery {
+    pub(crate) values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for OnDeleteQuery {
+    fn get_prefix(&self) -> String {
+        ON_DELETE_QUERY.to_string()
+    }
+}
+
+impl TySONMap for OnDeleteQuery {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match &k {
+            Primitive::PathToValue(_) | Primitive::RootPrimitive(_) => {}
+            _ => {
+                return Err(DBError::new(
+                    "Delete policies can be set only for paths and root",
+                ))
+            }
+        }
+        match &v {
+            Item::Primitive(Primitive::StringPrimitive(o)) => {
+                DeletePolicy::from_str(o.get_value())?;
+            }
+            Item::Primitive(Primitive::NullPrimitive(_)) => {}
+            _ => return Err(DBError::new("Delete policy must be a string or null")),
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::OnDeleteQuery(self))
+    }
+}
+
+impl OnDeleteQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+}
@@ -4,9 +4,22 @@ pub enum QueryOperation {
     GetOperation,
     FindOperation,
     UpdateOperation,
+    UpsertOperation,
+    ReplaceOperation,
+    IfVersionOperation,
+    AssertOperation,
     DeleteOperation,
     SortOperation,
     LimitOperation,
     OffsetOperation,
     ProjectOperation,
+    ReferencedByOperation,
+    OnDeleteOperation,
+    GroupOperation,
+    CountOperation,
+    DistinctOperation,
+    CursorOperation,
+    DepthOperation,
+    TraverseOperation,
+    ShortestPathOperation,
 }
@@ -0,0 +1,68 @@
+use crate::constants::{ADD_EXPRESSION, DIV_EXPRESSION, MUL_EXPRESSION, SUB_EXPRESSION};
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, TySONVector, VectorItem};
+
+/// `add` and `mul` take any number of operands, `sub` and `div` take two
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ArithmeticExpression {
+    operator: String,
+    pub(crate) items: Vec<Item>,
+}
+
+impl BaseTySONItemInterface for ArithmeticExpression {
+    fn get_prefix(&self) -> String {
+        self.operator.clone()
+    }
+}
+
+impl TySONVector for ArithmeticExpression {
+    fn new(prefix: String) -> Result<Self, DBError> {
+        Ok(Self {
+            operator: prefix,
+            items: vec![],
+        })
+    }
+
+    fn push(&mut self, item: Item) -> Result<bool, DBError> {
+        self.items.push(item);
+        Ok(true)
+    }
+
+    fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    fn to_item(self) -> Item {
+        Item::Vector(VectorItem::ArithmeticExpression(self))
+    }
+}
+
+impl ArithmeticExpression {
+    pub(crate) fn apply(&self, operands: &[NumberPrimitive]) -> Result<NumberPrimitive, DBError> {
+        match self.operator.as_str() {
+            ADD_EXPRESSION => Ok(operands
+                .iter()
+                .fold(NumberPrimitive::from(0.0), |acc, n| acc.add(n))),
+            MUL_EXPRESSION => Ok(operands
+                .iter()
+                .fold(NumberPrimitive::from(1.0), |acc, n| acc.mul(n))),
+            SUB_EXPRESSION | DIV_EXPRESSION => {
+                if operands.len() != 2 {
+                    return Err(DBError::new(
+                        format!("{} requires two operands", self.operator).as_str(),
+                    ));
+                }
+                let (a, b) = (operands[0].get_value(), operands[1].get_value());
+                if self.operator == SUB_EXPRESSION {
+                    Ok(NumberPrimitive::from(a - b))
+                } else if b == 0.0 {
+                    Err(DBError::new("Division by zero"))
+                } else {
+                    Ok(NumberPrimitive::from(a / b))
+                }
+            }
+            _ => Err(DBError::new("Unexpected arithmetic operator")),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::constants::CONCAT_EXPRESSION;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, TySONVector, VectorItem};
+
+/// Joins the resolved strings and numbers
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConcatExpression {
+    pub(crate) items: Vec<Item>,
+}
+
+impl BaseTySONItemInterface for ConcatExpression {
+    fn get_prefix(&self) -> String {
+        CONCAT_EXPRESSION.to_string()
+    }
+}
+
+impl TySONVector for ConcatExpression {
+    fn new(_: String) -> Result<Self, DBError> {
+        Ok(Self { items: vec![] })
+    }
+
+    fn push(&mut self, item: Item) -> Result<bool, DBError> {
+        self.items.push(item);
+        Ok(true)
+    }
+
+    fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    fn to_item(self) -> Item {
+        Item::Vector(VectorItem::ConcatExpression(self))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::constants::COND_EXPRESSION;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, TySONVector, VectorItem};
+
+/// `cond[condition, then, else]`.
+/// The condition is a find operator or a rule, which resolves to a bool.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CondExpression {
+    pub(crate) items: Vec<Item>,
+}
+
+impl BaseTySONItemInterface for CondExpression {
+    fn get_prefix(&self) -> String {
+        COND_EXPRESSION.to_string()
+    }
+}
+
+impl TySONVector for CondExpression {
+    fn new(_: String) -> Result<Self, DBError> {
+        Ok(Self { items: vec![] })
+    }
+
+    fn push(&mut self, item: Item) -> Result<bool, DBError> {
+        if self.items.len() == 3 {
+            return Err(DBError::new("Cond requires a condition and two values"));
+        }
+        self.items.push(item);
+        Ok(true)
+    }
+
+    fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    fn to_item(self) -> Item {
+        Item::Vector(VectorItem::CondExpression(self))
+    }
+}
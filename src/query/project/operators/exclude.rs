@@ -0,0 +1,28 @@
+use std::fmt::Debug;
+
+use crate::constants::EXCLUDE;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::primitive::TySONPrimitive;
+use crate::DBError;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct ExcludePrimitive;
+
+impl BaseTySONItemInterface for ExcludePrimitive {
+    fn get_prefix(&self) -> String {
+        EXCLUDE.to_string()
+    }
+}
+
+impl TySONPrimitive for ExcludePrimitive {
+    fn new(_: String, _: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {})
+    }
+
+    fn get_string_value(&self) -> String {
+        "".to_string()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::constants::FETCH_EXPRESSION;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, Primitive, TySONVector, VectorItem};
+
+/// `fetch[value|path|, n|depth|]` expands the links by the path only up to the depth.
+/// Deeper links are returned as they are.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FetchExpression {
+    pub(crate) items: Vec<Item>,
+}
+
+impl BaseTySONItemInterface for FetchExpression {
+    fn get_prefix(&self) -> String {
+        FETCH_EXPRESSION.to_string()
+    }
+}
+
+impl TySONVector for FetchExpression {
+    fn new(_: String) -> Result<Self, DBError> {
+        Ok(Self { items: vec![] })
+    }
+
+    fn push(&mut self, item: Item) -> Result<bool, DBError> {
+        match (self.items.len(), &item) {
+            (0, Item::Primitive(Primitive::PathToValue(_)))
+            | (1, Item::Primitive(Primitive::NumberPrimitive(_))) => {
+                self.items.push(item);
+                Ok(true)
+            }
+            _ => Err(DBError::new("Fetch requires a path and a depth")),
+        }
+    }
+
+    fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    fn to_item(self) -> Item {
+        Item::Vector(VectorItem::FetchExpression(self))
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use crate::constants::LINK_ID;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::primitive::TySONPrimitive;
+use crate::DBError;
+
+/// Returns the link stored by the path instead of the linked object.
+/// Without the path it returns the link of the object itself.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct LinkIdPrimitive {
+    pub(crate) path: String,
+}
+
+impl BaseTySONItemInterface for LinkIdPrimitive {
+    fn get_prefix(&self) -> String {
+        LINK_ID.to_string()
+    }
+}
+
+impl TySONPrimitive for LinkIdPrimitive {
+    fn new(_: String, value: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { path: value })
+    }
+
+    fn get_string_value(&self) -> String {
+        self.path.clone()
+    }
+}
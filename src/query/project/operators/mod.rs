@@ -1 +1,8 @@
+pub mod arithmetic;
+pub mod concat;
+pub mod cond;
+pub mod exclude;
+pub mod fetch;
 pub mod keep;
+pub mod link_id;
+pub mod version;
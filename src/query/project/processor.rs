@@ -1,39 +1,118 @@
-use crate::constants::NULL;
+use crate::constants::{INTERNAL_COLLECTION_NAME, NULL, PATH_SEPARATOR};
 use crate::data_types::map::storage::StorageMap;
+use crate::data_types::primitives::number::NumberPrimitive;
 use crate::data_types::vector::storage::StorageVector;
+use crate::query::find::compare::{compare, Res};
+use crate::query::project::operators::keep::KeepPrimitive;
 use crate::storage::buffer::InsertBuffer;
 use crate::{
-    DBError, Item, Link, PathToValue, Primitive, Storage, StringPrimitive, TySONMap,
-    TySONPrimitive, TySONVector,
+    DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap, TySONPrimitive,
+    TySONVector, VectorItem,
 };
 
+fn follow_links(
+    mut item: Item,
+    internal_only: bool,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Option<Item> {
+    while let Item::Primitive(Primitive::Link(l)) = &item {
+        if internal_only && l.collection_name != INTERNAL_COLLECTION_NAME {
+            break;
+        }
+        item = storage.get_stored_item(l, insert_buf)?;
+    }
+    Some(item)
+}
+
+/// Stored value by the path. Links to other objects at the end of the path are not followed.
+fn get_stored_value(
+    path: &str,
+    link: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Option<Item>, DBError> {
+    let mut item = Item::Primitive(Primitive::Link(link.clone()));
+    for sub_path in path.split(PATH_SEPARATOR) {
+        let found = match follow_links(item, false, storage, insert_buf) {
+            Some(Item::Map(MapItem::StorageMap(o))) => o.get_by_str(sub_path)?.cloned(),
+            Some(Item::Vector(VectorItem::StorageVector(o))) => o.get_by_str(sub_path)?.cloned(),
+            _ => None,
+        };
+        match found {
+            Some(o) => item = o,
+            None => return Ok(None),
+        }
+    }
+    Ok(follow_links(item, true, storage, insert_buf))
+}
+
+/// Resolves the operands of an expression
+fn resolve_all(
+    items: &[Item],
+    field: Option<&StringPrimitive>,
+    link: &Link,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+    depth: usize,
+) -> Result<Vec<Item>, DBError> {
+    let mut res: Vec<Item> = vec![];
+    for item in items {
+        res.push(resolve(
+            item.clone(),
+            field,
+            link,
+            storage,
+            insert_buf,
+            depth,
+        )?);
+    }
+    Ok(res)
+}
+
 pub fn resolve(
     rules: Item,
     field: Option<&StringPrimitive>,
     link: &Link,
     storage: &Storage,
     insert_buf: &InsertBuffer,
+    depth: usize,
 ) -> Result<Item, DBError> {
     let default = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
     match rules {
         Item::Primitive(Primitive::KeepPrimitive(_)) => {
-            let path = PathToValue::new("".to_string(), field.unwrap().get_string_value())?;
-            let res = storage.get_value_by_path(path, link.clone(), insert_buf)?;
-            match res {
-                Some(o) => {
-                    let item_to_fetch = o.value.unwrap_or(default);
-                    Ok(storage.fetch(&item_to_fetch, insert_buf, 0)?)
-                }
+            let path = field.unwrap().get_string_value();
+            match get_stored_value(path.as_str(), link, storage, insert_buf)? {
+                Some(o) => Ok(storage.fetch_part(&o, link, insert_buf, depth)?),
                 None => Ok(default),
             }
         }
-        Item::Primitive(Primitive::PathToValue(path)) => {
-            let res = storage.get_value_by_path(path.clone(), link.clone(), insert_buf)?;
-            match res {
-                Some(o) => {
-                    let item_to_fetch = o.value.unwrap_or(default);
-                    Ok(storage.fetch(&item_to_fetch, insert_buf, 0)?)
+        Item::Primitive(Primitive::VersionPrimitive(_)) => {
+            Ok(Item::Primitive(Primitive::NumberPrimitive(
+                NumberPrimitive::from(storage.get_version(link, insert_buf)),
+            )))
+        }
+        Item::Primitive(Primitive::LinkIdPrimitive(o)) => {
+            if o.path.is_empty() {
+                return Ok(Item::Primitive(Primitive::Link(link.clone())));
+            }
+            match get_stored_value(o.path.as_str(), link, storage, insert_buf)? {
+                Some(Item::Primitive(Primitive::Link(l))) => {
+                    Ok(Item::Primitive(Primitive::Link(l)))
                 }
+                _ => Ok(default),
+            }
+        }
+        Item::Primitive(Primitive::PathToValue(path)) if path.is_wildcard() => {
+            let mut new_vec = StorageVector::new("".to_string())?;
+            for found in storage.get_values_by_path(&path, link, insert_buf)? {
+                new_vec.push(storage.fetch_part(&found, link, insert_buf, depth)?)?;
+            }
+            Ok(new_vec.to_item())
+        }
+        Item::Primitive(Primitive::PathToValue(path)) => {
+            match get_stored_value(path.value.as_str(), link, storage, insert_buf)? {
+                Some(o) => Ok(storage.fetch_part(&o, link, insert_buf, depth)?),
                 None => Ok(default),
             }
         }
@@ -42,6 +121,67 @@ pub fn resolve(
         Item::Primitive(Primitive::UTSPrimitive(_)) => Ok(rules.clone()),
         Item::Primitive(Primitive::BoolPrimitive(_)) => Ok(rules.clone()),
         Item::Primitive(Primitive::NullPrimitive(_)) => Ok(rules.clone()),
+        Item::Vector(VectorItem::ConcatExpression(o)) => {
+            let mut res = String::new();
+            for item in resolve_all(&o.items, field, link, storage, insert_buf, depth)? {
+                match item {
+                    Item::Primitive(Primitive::StringPrimitive(v)) => res.push_str(v.get_value()),
+                    Item::Primitive(Primitive::NumberPrimitive(v)) => {
+                        res.push_str(v.get_string_value().as_str())
+                    }
+                    Item::Primitive(Primitive::NullPrimitive(_)) => return Ok(default),
+                    _ => return Err(DBError::new("Concat supports only strings and numbers")),
+                }
+            }
+            Ok(Item::from(Primitive::from(StringPrimitive::from(
+                res.as_str(),
+            ))))
+        }
+        Item::Vector(VectorItem::ArithmeticExpression(o)) => {
+            let mut operands: Vec<NumberPrimitive> = vec![];
+            for item in resolve_all(&o.items, field, link, storage, insert_buf, depth)? {
+                match item {
+                    Item::Primitive(Primitive::NumberPrimitive(v)) => operands.push(v),
+                    Item::Primitive(Primitive::NullPrimitive(_)) => return Ok(default),
+                    _ => return Err(DBError::new("Arithmetic expressions support only numbers")),
+                }
+            }
+            Ok(Item::Primitive(Primitive::NumberPrimitive(
+                o.apply(&operands)?,
+            )))
+        }
+        Item::Vector(VectorItem::CondExpression(o)) => {
+            if o.items.len() != 3 {
+                return Err(DBError::new("Cond requires a condition and two values"));
+            }
+            let matched = match &o.items[0] {
+                Item::Primitive(_) => matches!(
+                    resolve(o.items[0].clone(), field, link, storage, insert_buf, depth)?,
+                    Item::Primitive(Primitive::BoolPrimitive(b)) if b.get_value()
+                ),
+                op => compare(op, link, storage, insert_buf)? == Res::True,
+            };
+            let branch = if matched {
+                o.items[1].clone()
+            } else {
+                o.items[2].clone()
+            };
+            resolve(branch, field, link, storage, insert_buf, depth)
+        }
+        Item::Vector(VectorItem::FetchExpression(o)) => match (o.items.first(), o.items.get(1)) {
+            (
+                Some(Item::Primitive(Primitive::PathToValue(path))),
+                Some(Item::Primitive(Primitive::NumberPrimitive(n))),
+            ) if n.get_value() >= 0.0 => {
+                match get_stored_value(path.value.as_str(), link, storage, insert_buf)? {
+                    Some(value) => {
+                        storage.fetch_part(&value, link, insert_buf, n.get_value() as usize)
+                    }
+                    None => Ok(default),
+                }
+            }
+            _ => Err(DBError::new("Fetch requires a path and a depth")),
+        },
         Item::Vector(v) => {
             let mut new_vec = StorageVector::new("".to_string())?;
             for (i, v) in v.get_items().iter().enumerate() {
@@ -56,15 +196,44 @@ pub fn resolve(
                     link,
                     storage,
                     insert_buf,
+                    depth,
                 )?)?;
             }
             Ok(new_vec.to_item())
         }
         Item::Map(m) => {
-            let mut new_map = StorageMap::new("".to_string())?;
-            for (k, v) in m.get_items() {
+            let items = m.get_items();
+            // With the exclusion the rules are applied to the whole object
+            let excluding = items
+                .iter()
+                .any(|(_, v)| matches!(v, Item::Primitive(Primitive::ExcludePrimitive(_))));
+            let mut new_map = if excluding {
+                let base = match field {
+                    Some(_) => resolve(
+                        Item::Primitive(Primitive::KeepPrimitive(KeepPrimitive)),
+                        field,
+                        link,
+                        storage,
+                        insert_buf,
+                        depth,
+                    )?,
+                    None => storage.get_item_by_link(link, insert_buf, depth, None)?,
+                };
+                match base {
+                    Item::Map(MapItem::StorageMap(o)) => o,
+                    Item::Primitive(Primitive::NullPrimitive(_)) => return Ok(base),
+                    _ => return Err(DBError::new("Exclusion can be applied only to objects")),
+                }
+            } else {
+                StorageMap::new("".to_string())?
+            };
+            for (k, v) in items {
                 match &k {
                     Primitive::StringPrimitive(s) => {
+                        if let Item::Primitive(Primitive::ExcludePrimitive(_)) = v {
+                            new_map.remove_by_str(s.get_value());
+                            continue;
+                        }
                         let mut new_field = s.get_string_value();
                         if field.is_some() {
                             new_field =
@@ -79,6 +248,7 @@ pub fn resolve(
                                 link,
                                 storage,
                                 insert_buf,
+                                depth,
                             )?,
                         )?;
                     }
@@ -1,11 +1,16 @@
 use crate::constants::QUERY_SET;
+use crate::query::assert::query::AssertQuery;
+use crate::query::count::query::CountQuery;
 use crate::query::delete::query::DeleteQuery;
+use crate::query::distinct::query::DistinctQuery;
 use crate::query::find::query::FindQuery;
 use crate::query::get::query::GetQuery;
-use crate::query::insert::query::InsertQuery;
+use crate::query::insert::query::{InsertQuery, InsertWithIdsQuery};
+use crate::query::on_delete::query::OnDeleteQuery;
+use crate::query::referenced_by::query::ReferencedByQuery;
 use crate::query::update::query::UpdateQuery;
 use crate::tyson::item::BaseTySONItemInterface;
-use crate::{DBError, Item, TySONVector, VectorItem};
+use crate::{DBError, Item, TySONMap, TySONVector, VectorItem};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct QuerySet {
@@ -45,6 +50,14 @@ impl From<InsertQuery> for QuerySet {
     }
 }
 
+impl From<InsertWithIdsQuery> for QuerySet {
+    fn from(q: InsertWithIdsQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
+
 impl From<GetQuery> for QuerySet {
     fn from(q: GetQuery) -> Self {
         Self {
@@ -76,3 +89,43 @@ impl From<DeleteQuery> for QuerySet {
         }
     }
 }
+
+impl From<OnDeleteQuery> for QuerySet {
+    fn from(q: OnDeleteQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
+
+impl From<CountQuery> for QuerySet {
+    fn from(q: CountQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
+
+impl From<DistinctQuery> for QuerySet {
+    fn from(q: DistinctQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
+
+impl From<AssertQuery> for QuerySet {
+    fn from(q: AssertQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
+
+impl From<ReferencedByQuery> for QuerySet {
+    fn from(q: ReferencedByQuery) -> Self {
+        Self {
+            items: vec![q.to_item()],
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::constants::STORAGE_MAP;
+use crate::query::find::processor::get_ids_list;
+use crate::response::ids::ResponseIds;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{
+    DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap, VectorItem,
+};
+
+pub fn referenced_by(
+    storage: &Storage,
+    collection_name: String,
+    buf: &FilterBuffer,
+    insert_buf: &InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    let ids = if is_first {
+        get_ids_list(storage, collection_name, insert_buf)
+    } else {
+        buf.ids.clone()
+    };
+
+    // collection name -> path -> linking documents
+    let mut found: HashMap<String, HashMap<String, HashSet<Link>>> = HashMap::new();
+    let mut documents: HashSet<Link> = HashSet::new();
+    for id in &ids {
+        for (link, path) in storage.get_references(id, insert_buf)? {
+            documents.insert(link.clone());
+            found
+                .entry(link.collection_name.clone())
+                .or_default()
+                .entry(path)
+                .or_default()
+                .insert(link);
+        }
+    }
+
+    let mut data = MapItem::new(STORAGE_MAP.to_string())?;
+    for (collection, paths) in found {
+        let mut paths_map = MapItem::new(STORAGE_MAP.to_string())?;
+        for (path, links) in paths {
+            paths_map.insert(
+                Primitive::from(StringPrimitive::from(path.as_str())),
+                Item::from(VectorItem::ResponseIds(ResponseIds::from(links))),
+            )?;
+        }
+        data.insert(
+            Primitive::from(StringPrimitive::from(collection.as_str())),
+            Item::Map(paths_map),
+        )?;
+    }
+    let meta = Meta::FindMeta(FindMeta::new(documents.len()));
+    Ok(QueryResponse::new(
+        Item::Map(data),
+        meta,
+        QueryStatus::Ready,
+    ))
+}
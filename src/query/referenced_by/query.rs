@@ -0,0 +1,39 @@
+use std::fmt::Debug;
+
+use crate::constants::REFERENCED_BY_QUERY;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::primitive::TySONPrimitive;
+use crate::{DBError, Item, Primitive};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct ReferencedByQuery;
+
+impl BaseTySONItemInterface for ReferencedByQuery {
+    fn get_prefix(&self) -> String {
+        REFERENCED_BY_QUERY.to_string()
+    }
+}
+
+impl TySONPrimitive for ReferencedByQuery {
+    fn new(_: String, _: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {})
+    }
+
+    fn get_string_value(&self) -> String {
+        "".to_string()
+    }
+}
+
+impl ReferencedByQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Primitive(Primitive::ReferencedByQuery(self))
+    }
+}
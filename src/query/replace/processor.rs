@@ -0,0 +1,56 @@
+use crate::constants::{DELETED, INTERNAL_COLLECTION_NAME};
+use crate::query::replace::query::ReplaceQuery;
+use crate::response::meta::{Meta, UpdateMeta};
+use crate::response::objects::ResponseObjects;
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::storage::references::links_to;
+use crate::{DBError, Item, Link, Primitive, Storage, TySONMap};
+
+/// Collects the `_internal` records owned by the item.
+/// Links to the other collections are references, so they are not followed.
+fn collect_owned(storage: &Storage, item: &Item, insert_buf: &InsertBuffer, owned: &mut Vec<Link>) {
+    for link in links_to(item) {
+        if link.collection_name == INTERNAL_COLLECTION_NAME && !owned.contains(link) {
+            owned.push(link.clone());
+            if let Some(value) = storage.get_stored_item(link, insert_buf) {
+                collect_owned(storage, &value, insert_buf, owned);
+            }
+        }
+    }
+}
+
+pub fn replace(
+    storage: &Storage,
+    query: &ReplaceQuery,
+    filter_buf: &FilterBuffer,
+    insert_buf: &mut InsertBuffer,
+) -> Result<QueryResponse, DBError> {
+    let mut previous = ResponseObjects::new("".to_string())?;
+    for id in &filter_buf.ids {
+        let stored = match storage.get_stored_item(id, insert_buf) {
+            Some(Item::Primitive(Primitive::DeletedPrimitive(_))) | None => continue,
+            Some(item) => item,
+        };
+        previous.insert(
+            Primitive::from(id.clone()),
+            storage.get_item_by_link(id, insert_buf, storage.fetch_depth, None)?,
+        )?;
+
+        let mut owned: Vec<Link> = vec![];
+        collect_owned(storage, &stored, insert_buf, &mut owned);
+        for link in owned {
+            insert_buf.insert(
+                link,
+                Item::from(Primitive::new(DELETED.to_string(), "".to_string())?),
+            );
+        }
+        storage.insert_item_by_link(id.clone(), insert_buf, query.get_value().clone())?;
+    }
+    let meta = Meta::UpdateMeta(UpdateMeta::new(previous.values.len()));
+    Ok(QueryResponse::new(
+        previous.to_item(),
+        meta,
+        QueryStatus::Ready,
+    ))
+}
@@ -0,0 +1,55 @@
+use std::fmt::Debug;
+
+use crate::constants::REPLACE_QUERY;
+use crate::data_types::modifier::ModifierItem;
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{DBError, Item, MapItem, VectorItem};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReplaceQuery {
+    value: Box<Item>,
+}
+
+impl BaseTySONItemInterface for ReplaceQuery {
+    fn get_prefix(&self) -> String {
+        REPLACE_QUERY.to_string()
+    }
+}
+
+impl TySONModifier for ReplaceQuery {
+    fn new(_: String, value: Item) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        match value {
+            Item::Primitive(_)
+            | Item::Map(MapItem::StorageMap(_))
+            | Item::Vector(VectorItem::StorageVector(_)) => Ok(Self {
+                value: Box::new(value),
+            }),
+            _ => Err(DBError::new(
+                "Replace query can contain only primitives, maps or vectors",
+            )),
+        }
+    }
+
+    fn get_serialized_value(&self) -> String {
+        self.value.serialize()
+    }
+}
+
+impl ReplaceQuery {
+    pub fn get_value(&self) -> &Item {
+        self.value.as_ref()
+    }
+
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+
+    pub fn to_item(self) -> Item {
+        Item::Modifier(ModifierItem::ReplaceQuery(self))
+    }
+}
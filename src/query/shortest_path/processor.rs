@@ -0,0 +1,71 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::constants::{FETCH_DEPTH_LIMIT, NULL};
+use crate::query::find::processor::get_ids_list;
+use crate::query::shortest_path::query::ShortestPathQuery;
+use crate::query::traverse::processor::{get_hops, get_path, next_hop};
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Link, Primitive, Storage};
+
+fn get_target(query: &ShortestPathQuery) -> Result<&Link, DBError> {
+    match &query.to {
+        Some(Primitive::Link(o)) => Ok(o),
+        _ => Err(DBError::new("Shortest path target must be a link")),
+    }
+}
+
+/// Keeps the found path in the buffer. It starts with one of the found objects
+/// and ends with the target. The buffer is empty, if the target is unreachable
+pub fn shortest_path(
+    storage: &Storage,
+    collection_name: String,
+    query: &ShortestPathQuery,
+    buf: &mut FilterBuffer,
+    insert_buf: &InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    let (path, filter) = get_path(&query.path)?;
+    let target = get_target(query)?;
+    let max = get_hops(&query.max, FETCH_DEPTH_LIMIT as usize)?;
+
+    let mut sources = if is_first {
+        get_ids_list(storage, collection_name, insert_buf)
+    } else {
+        buf.ids.clone()
+    };
+    let mut visited: HashSet<Link> = HashSet::from_iter(sources.iter().cloned());
+    let mut parents: HashMap<Link, Link> = HashMap::new();
+    let mut found = visited.contains(target);
+    for _ in 0..max {
+        if found || sources.is_empty() {
+            break;
+        }
+        let mut targets: Vec<Link> = vec![];
+        for source in &sources {
+            for next in next_hop(path, filter, source, &mut visited, storage, insert_buf)? {
+                parents.insert(next.clone(), source.clone());
+                targets.push(next);
+            }
+        }
+        found = parents.contains_key(target);
+        sources = targets;
+    }
+
+    let mut found_ids: Vec<Link> = vec![];
+    if found {
+        let mut current = target.clone();
+        found_ids.push(current.clone());
+        while let Some(parent) = parents.get(&current) {
+            found_ids.push(parent.clone());
+            current = parent.clone();
+        }
+        found_ids.reverse();
+    }
+
+    buf.update(found_ids);
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(buf.ids.len()));
+    Ok(QueryResponse::new(data, meta, QueryStatus::NotFetched))
+}
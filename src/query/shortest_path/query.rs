@@ -0,0 +1,104 @@
+use crate::constants::{MAX_OPTION, SHORTEST_PATH_QUERY, TO_OPTION};
+use crate::query::operations::QueryOperation;
+use crate::query::traverse::query::{check_hops, check_path};
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap};
+
+/// Finds the shortest chain of links from the found objects to the target
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShortestPathQuery {
+    pub(crate) path: Vec<(Primitive, Item)>,
+    pub(crate) to: Option<Primitive>,
+    pub(crate) max: Option<Primitive>,
+}
+
+impl BaseTySONItemInterface for ShortestPathQuery {
+    fn get_prefix(&self) -> String {
+        SHORTEST_PATH_QUERY.to_string()
+    }
+}
+
+impl TySONMap for ShortestPathQuery {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            path: vec![],
+            to: None,
+            max: None,
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match &k {
+            Primitive::StringPrimitive(o) if o.get_value() == TO_OPTION => match v {
+                Item::Primitive(to @ Primitive::Link(_))
+                | Item::Primitive(to @ Primitive::Param(_))
+                | Item::Primitive(to @ Primitive::StepResult(_)) => {
+                    self.to = Some(to);
+                    Ok(true)
+                }
+                _ => Err(DBError::new("Shortest path target must be a link")),
+            },
+            Primitive::StringPrimitive(o) if o.get_value() == MAX_OPTION => {
+                self.max = Some(check_hops(v)?);
+                Ok(true)
+            }
+            Primitive::StringPrimitive(_) => Err(DBError::new(
+                "Shortest path query supports only to and max options",
+            )),
+            _ => {
+                self.path.push(check_path(&self.path, k, v)?);
+                Ok(true)
+            }
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.path {
+            ve.push((k.clone(), v.clone()));
+        }
+        if let Some(to) = &self.to {
+            ve.push((
+                Primitive::from(StringPrimitive::from(TO_OPTION)),
+                Item::from(to.clone()),
+            ));
+        }
+        if let Some(max) = &self.max {
+            ve.push((
+                Primitive::from(StringPrimitive::from(MAX_OPTION)),
+                Item::from(max.clone()),
+            ));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::ShortestPathQuery(self))
+    }
+}
+
+impl ShortestPathQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![
+            QueryOperation::FindOperation,
+            QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
+            QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::GroupOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
+            QueryOperation::LimitOperation,
+            QueryOperation::OffsetOperation,
+            QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+        ]
+    }
+}
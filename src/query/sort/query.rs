@@ -126,9 +126,20 @@ impl SortQuery {
         vec![
             QueryOperation::FindOperation,
             QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
             QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::GroupOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
             QueryOperation::LimitOperation,
             QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+            QueryOperation::ShortestPathOperation,
         ]
     }
 }
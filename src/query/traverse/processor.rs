@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use crate::constants::{FETCH_DEPTH_LIMIT, INTERNAL_COLLECTION_NAME, NULL};
+use crate::query::find::compare::{check_bool, get_linked_ids, Res};
+use crate::query::find::processor::get_ids_list;
+use crate::query::traverse::query::TraverseQuery;
+use crate::response::meta::{FindMeta, Meta};
+use crate::response::{QueryResponse, QueryStatus};
+use crate::storage::buffer::{FilterBuffer, InsertBuffer};
+use crate::{DBError, Item, Link, PathToValue, Primitive, Storage};
+
+pub(crate) fn get_hops(value: &Option<Primitive>, default: usize) -> Result<usize, DBError> {
+    match value {
+        None => Ok(default),
+        Some(Primitive::NumberPrimitive(n))
+            if n.get_value() >= 0.0
+                && n.get_value() <= FETCH_DEPTH_LIMIT as f64
+                && n.get_value().fract() == 0.0 =>
+        {
+            Ok(n.get_value() as usize)
+        }
+        _ => Err(DBError::new("Hops are out of range")),
+    }
+}
+
+pub(crate) fn get_path(path: &[(Primitive, Item)]) -> Result<(&PathToValue, &Item), DBError> {
+    match path.first() {
+        Some((Primitive::PathToValue(o), filter)) => Ok((o, filter)),
+        _ => Err(DBError::new("Path to follow is not set")),
+    }
+}
+
+/// Returns the linked objects, which were not visited yet and match the filter
+pub(crate) fn next_hop(
+    path: &PathToValue,
+    filter: &Item,
+    source: &Link,
+    visited: &mut HashSet<Link>,
+    storage: &Storage,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<Link>, DBError> {
+    let mut res: Vec<Link> = vec![];
+    for target in get_linked_ids(path, source, storage, insert_buf)? {
+        // values of the path, which are not links, are stored in the internal collection
+        if target.collection_name == INTERNAL_COLLECTION_NAME {
+            continue;
+        }
+        // cycles are cut here, each object is visited once
+        if !visited.insert(target.clone()) {
+            continue;
+        }
+        if check_bool(filter, &target, storage, insert_buf)? == Res::True {
+            res.push(target);
+        }
+    }
+    Ok(res)
+}
+
+pub fn traverse(
+    storage: &Storage,
+    collection_name: String,
+    query: &TraverseQuery,
+    buf: &mut FilterBuffer,
+    insert_buf: &InsertBuffer,
+    is_first: bool,
+) -> Result<QueryResponse, DBError> {
+    let (path, filter) = get_path(&query.path)?;
+    let min = get_hops(&query.min, 1)?;
+    let max = get_hops(&query.max, 1)?;
+    if min > max {
+        return Err(DBError::new("Min hops can not be greater than max hops"));
+    }
+
+    let mut sources = if is_first {
+        get_ids_list(storage, collection_name, insert_buf)
+    } else {
+        buf.ids.clone()
+    };
+    // The start objects can be reached from each other, so they are skipped
+    // only when they are already returned
+    let mut visited: HashSet<Link> = HashSet::new();
+    let mut found_ids: Vec<Link> = vec![];
+    if min == 0 {
+        visited.extend(sources.iter().cloned());
+        found_ids.extend(sources.iter().cloned());
+    }
+    for hop in 1..=max {
+        let mut targets: Vec<Link> = vec![];
+        for source in &sources {
+            targets.extend(next_hop(
+                path,
+                filter,
+                source,
+                &mut visited,
+                storage,
+                insert_buf,
+            )?);
+        }
+        if hop >= min {
+            found_ids.extend(targets.iter().cloned());
+        }
+        if targets.is_empty() {
+            break;
+        }
+        sources = targets;
+    }
+
+    buf.update(found_ids);
+    let data = Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?);
+    let meta = Meta::FindMeta(FindMeta::new(buf.ids.len()));
+    Ok(QueryResponse::new(data, meta, QueryStatus::NotFetched))
+}
@@ -0,0 +1,133 @@
+use crate::constants::{MAX_OPTION, MIN_OPTION, TRAVERSE_QUERY};
+use crate::query::operations::QueryOperation;
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::{DBError, Item, MapItem, Primitive, StringPrimitive, TySONMap};
+
+/// Follows the links of the path from the found objects
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraverseQuery {
+    pub(crate) path: Vec<(Primitive, Item)>,
+    pub(crate) min: Option<Primitive>,
+    pub(crate) max: Option<Primitive>,
+}
+
+impl BaseTySONItemInterface for TraverseQuery {
+    fn get_prefix(&self) -> String {
+        TRAVERSE_QUERY.to_string()
+    }
+}
+
+impl TySONMap for TraverseQuery {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            path: vec![],
+            min: None,
+            max: None,
+        })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match &k {
+            Primitive::StringPrimitive(o) if o.get_value() == MIN_OPTION => {
+                self.min = Some(check_hops(v)?);
+                Ok(true)
+            }
+            Primitive::StringPrimitive(o) if o.get_value() == MAX_OPTION => {
+                self.max = Some(check_hops(v)?);
+                Ok(true)
+            }
+            Primitive::StringPrimitive(_) => Err(DBError::new(
+                "Traverse query supports only min and max options",
+            )),
+            _ => {
+                self.path.push(check_path(&self.path, k, v)?);
+                Ok(true)
+            }
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.path {
+            ve.push((k.clone(), v.clone()));
+        }
+        if let Some(min) = &self.min {
+            ve.push((
+                Primitive::from(StringPrimitive::from(MIN_OPTION)),
+                Item::from(min.clone()),
+            ));
+        }
+        if let Some(max) = &self.max {
+            ve.push((
+                Primitive::from(StringPrimitive::from(MAX_OPTION)),
+                Item::from(max.clone()),
+            ));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::TraverseQuery(self))
+    }
+}
+
+impl TraverseQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![
+            QueryOperation::FindOperation,
+            QueryOperation::UpdateOperation,
+            QueryOperation::ReplaceOperation,
+            QueryOperation::IfVersionOperation,
+            QueryOperation::AssertOperation,
+            QueryOperation::DeleteOperation,
+            QueryOperation::ReferencedByOperation,
+            QueryOperation::GroupOperation,
+            QueryOperation::CountOperation,
+            QueryOperation::DistinctOperation,
+            QueryOperation::SortOperation,
+            QueryOperation::LimitOperation,
+            QueryOperation::OffsetOperation,
+            QueryOperation::ProjectOperation,
+            QueryOperation::CursorOperation,
+            QueryOperation::DepthOperation,
+            QueryOperation::TraverseOperation,
+            QueryOperation::ShortestPathOperation,
+        ]
+    }
+}
+
+/// Hops are checked when the query runs, because they can be placeholders
+pub(crate) fn check_hops(value: Item) -> Result<Primitive, DBError> {
+    match value {
+        Item::Primitive(o @ Primitive::NumberPrimitive(_))
+        | Item::Primitive(o @ Primitive::Param(_))
+        | Item::Primitive(o @ Primitive::StepResult(_)) => Ok(o),
+        _ => Err(DBError::new("Hops must be numbers")),
+    }
+}
+
+/// The only path of the graph queries. The value is a find expression for the linked objects
+pub(crate) fn check_path(
+    current: &[(Primitive, Item)],
+    k: Primitive,
+    v: Item,
+) -> Result<(Primitive, Item), DBError> {
+    if !current.is_empty() {
+        return Err(DBError::new("Only one path can be followed"));
+    }
+    if !matches!(k, Primitive::PathToValue(_)) {
+        return Err(DBError::new("Only paths can be followed"));
+    }
+    match v {
+        Item::Primitive(Primitive::BoolPrimitive(_))
+        | Item::Map(_)
+        | Item::Vector(_)
+        | Item::Modifier(_) => Ok((k, v)),
+        _ => Err(DBError::new(
+            "Links can be filtered only by find expressions",
+        )),
+    }
+}
@@ -0,0 +1,46 @@
+use crate::constants::ADD_TO_SET_OPERATOR;
+use crate::query::update::operators::{check_key, check_value, is_create_paths_option};
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddToSetOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for AddToSetOperator {
+    fn get_prefix(&self) -> String {
+        ADD_TO_SET_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for AddToSetOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        check_key(&k, &v, "AddToSet")?;
+        if !is_create_paths_option(&k) {
+            check_value(&v, "Add to set")?;
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::AddToSetOperator(self))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::constants::INSERT_AT_OPERATOR;
+use crate::query::update::operators::{
+    check_key, check_value, get_position, is_create_paths_option,
+};
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsertAtOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for InsertAtOperator {
+    fn get_prefix(&self) -> String {
+        INSERT_AT_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for InsertAtOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        check_key(&k, &v, "InsertAt")?;
+        if !is_create_paths_option(&k) {
+            match &k {
+                Primitive::PathToValue(path) if get_position(path).is_some() => {}
+                _ => {
+                    return Err(DBError::new(
+                        "Insert at operator keys must be paths to vector positions",
+                    ))
+                }
+            }
+            check_value(&v, "Insert at")?;
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::InsertAtOperator(self))
+    }
+}
@@ -0,0 +1,49 @@
+use crate::constants::MAX_OPERATOR;
+use crate::query::update::operators::{check_key, is_create_paths_option};
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for MaxOperator {
+    fn get_prefix(&self) -> String {
+        MAX_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for MaxOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        check_key(&k, &v, "Max")?;
+        if !is_create_paths_option(&k) {
+            match &v {
+                Item::Primitive(_) => {}
+                _ => return Err(DBError::new("Max operator supports primitives only")),
+            }
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::MaxOperator(self))
+    }
+}
@@ -1,2 +1,66 @@
+use crate::constants::{CREATE_PATHS_OPTION, PATH_SEPARATOR};
+use crate::data_types::primitives::path::PathToValue;
+use crate::{DBError, Item, MapItem, Primitive, VectorItem};
+
+pub(crate) mod add_to_set;
 pub(crate) mod inc;
+pub(crate) mod insert_at;
+pub(crate) mod max;
+pub(crate) mod min;
+pub(crate) mod mul;
+pub(crate) mod now;
+pub(crate) mod pop;
+pub(crate) mod pull;
+pub(crate) mod push;
+pub(crate) mod rename;
 pub(crate) mod set;
+pub(crate) mod unset;
+
+pub(crate) fn is_create_paths_option(k: &Primitive) -> bool {
+    matches!(k, Primitive::StringPrimitive(o) if o.get_value() == CREATE_PATHS_OPTION)
+}
+
+/// Checks the key of the update operator: a path, root or the `create_paths` option
+pub(crate) fn check_key(k: &Primitive, v: &Item, operator: &str) -> Result<(), DBError> {
+    match k {
+        Primitive::PathToValue(_) | Primitive::RootPrimitive(_) => Ok(()),
+        _ if is_create_paths_option(k) => match v {
+            Item::Primitive(Primitive::BoolPrimitive(_)) => Ok(()),
+            _ => Err(DBError::new("Create paths option must be a bool")),
+        },
+        _ => Err(DBError::new(
+            format!("{} operator keys must be paths or root", operator).as_str(),
+        )),
+    }
+}
+
+/// Checks the value stored by the update operator: a primitive, map or vector
+pub(crate) fn check_value(v: &Item, operator: &str) -> Result<(), DBError> {
+    match v {
+        Item::Primitive(_)
+        | Item::Map(MapItem::StorageMap(_))
+        | Item::Vector(VectorItem::StorageVector(_)) => Ok(()),
+        _ => Err(DBError::new(
+            format!(
+                "{} operator values must be primitives, maps or vectors",
+                operator
+            )
+            .as_str(),
+        )),
+    }
+}
+
+/// Splits the path to a vector position into the path to the vector and the index.
+/// The vector path is `None` when the document itself is the vector.
+pub(crate) fn get_position(path: &PathToValue) -> Option<(Option<PathToValue>, usize)> {
+    let (vector_path, index) = match path.value.rsplit_once(PATH_SEPARATOR) {
+        Some((vector_path, index)) => (
+            Some(PathToValue {
+                value: vector_path.to_string(),
+            }),
+            index,
+        ),
+        None => (None, path.value.as_str()),
+    };
+    index.parse::<usize>().ok().map(|i| (vector_path, i))
+}
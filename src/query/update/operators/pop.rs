@@ -0,0 +1,50 @@
+use crate::constants::POP_OPERATOR;
+use crate::query::update::operators::{check_key, is_create_paths_option};
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PopOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for PopOperator {
+    fn get_prefix(&self) -> String {
+        POP_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for PopOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        check_key(&k, &v, "Pop")?;
+        if !is_create_paths_option(&k) {
+            match &v {
+                Item::Primitive(Primitive::NumberPrimitive(n))
+                    if n.get_value() == 1.0 || n.get_value() == -1.0 => {}
+                _ => return Err(DBError::new("Pop operator values must be 1 or -1")),
+            }
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::PopOperator(self))
+    }
+}
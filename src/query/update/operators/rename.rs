@@ -0,0 +1,61 @@
+use crate::constants::{PATH_SEPARATOR, RENAME_OPERATOR};
+use crate::query::update::operators::is_create_paths_option;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+fn is_nested(path: &str, other: &str) -> bool {
+    path == other || path.starts_with(format!("{}{}", other, PATH_SEPARATOR).as_str())
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenameOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for RenameOperator {
+    fn get_prefix(&self) -> String {
+        RENAME_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for RenameOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match (&k, &v) {
+            (Primitive::PathToValue(from), Item::Primitive(Primitive::PathToValue(to))) => {
+                if from.is_wildcard() || to.is_wildcard() {
+                    return Err(DBError::new("Wildcard paths can not be renamed"));
+                }
+                if is_nested(&from.value, &to.value) || is_nested(&to.value, &from.value) {
+                    return Err(DBError::new("Rename paths conflict"));
+                }
+            }
+            (_, Item::Primitive(Primitive::BoolPrimitive(_))) if is_create_paths_option(&k) => {}
+            _ if is_create_paths_option(&k) => {
+                return Err(DBError::new("Create paths option must be a bool"))
+            }
+            _ => return Err(DBError::new("Rename operator can map paths to paths only")),
+        }
+        self.values.push((k, v));
+        Ok(true)
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::RenameOperator(self))
+    }
+}
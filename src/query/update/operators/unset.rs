@@ -0,0 +1,49 @@
+use crate::constants::UNSET_OPERATOR;
+use crate::{DBError, Item, MapItem, Primitive, TySONMap};
+
+use crate::tyson::item::BaseTySONItemInterface;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnsetOperator {
+    values: Vec<(Primitive, Item)>,
+}
+
+impl BaseTySONItemInterface for UnsetOperator {
+    fn get_prefix(&self) -> String {
+        UNSET_OPERATOR.to_string()
+    }
+}
+
+impl TySONMap for UnsetOperator {
+    fn new(_: String) -> Result<Self, DBError>
+    where
+        Self: Sized,
+    {
+        Ok(Self { values: vec![] })
+    }
+
+    fn insert(&mut self, k: Primitive, v: Item) -> Result<bool, DBError> {
+        match (&k, &v) {
+            (Primitive::PathToValue(_), Item::Primitive(Primitive::NullPrimitive(_))) => {
+                self.values.push((k, v));
+                Ok(true)
+            }
+            (Primitive::PathToValue(_), _) => {
+                Err(DBError::new("Unset operator values must be null"))
+            }
+            _ => Err(DBError::new("Unset operator keys must be paths")),
+        }
+    }
+
+    fn get_items(&self) -> Vec<(Primitive, Item)> {
+        let mut ve: Vec<(Primitive, Item)> = vec![];
+        for (k, v) in &self.values {
+            ve.push((k.clone(), v.clone()));
+        }
+        ve
+    }
+
+    fn to_item(self) -> Item {
+        Item::Map(MapItem::UnsetOperator(self))
+    }
+}
@@ -1,10 +1,22 @@
-use crate::constants::INTERNAL_COLLECTION_NAME;
-use crate::query::update::query::UpdateQuery;
+use crate::constants::{INTERNAL_COLLECTION_NAME, PATH_SEPARATOR, STORAGE_VECTOR};
+use crate::data_types::map::storage::StorageMap;
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::data_types::primitives::path::PathToValue;
+use crate::data_types::primitives::root::RootPrimitive;
+use crate::data_types::primitives::unix_timestamp::UTSPrimitive;
+use crate::data_types::vector::storage::StorageVector;
+use crate::query::find::compare::{compare, Res};
+use crate::query::find::query::FindQuery;
+use crate::query::update::operators::{get_position, is_create_paths_option};
+use crate::query::update::query::{UpdateQuery, UpsertQuery};
 use crate::response::ids::ResponseIds;
 use crate::response::meta::{Meta, UpdateMeta};
 use crate::response::{QueryResponse, QueryStatus};
-use crate::{DBError, Item, Link, MapItem, Primitive, Storage, TySONMap, TySONVector, VectorItem};
-use std::collections::HashSet;
+use crate::{
+    DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap, TySONVector,
+    VectorItem,
+};
+use std::collections::{HashMap, HashSet};
 
 use crate::storage::buffer::{FilterBuffer, InsertBuffer};
 
@@ -58,38 +70,287 @@ fn update_item(
     }
 }
 
-fn manage_operator(
+fn numeric_value(
+    found_item: &FoundItem,
+    operator: &str,
+) -> Result<Option<NumberPrimitive>, DBError> {
+    match found_item.get_value() {
+        None => Ok(None),
+        Some(Item::Primitive(Primitive::NumberPrimitive(o))) => Ok(Some(o)),
+        Some(_) => Err(DBError::new(
+            format!("{} operator supports numbers only", operator).as_str(),
+        )),
+    }
+}
+
+fn unset(found_item: FoundItem, insert_buf: &mut InsertBuffer) -> Result<bool, DBError> {
+    match found_item {
+        FoundItem::FoundSubItem(FoundSubItem {
+            container_id,
+            container_value: Item::Map(MapItem::StorageMap(mut container)),
+            key,
+            value: Some(_),
+        }) => {
+            container.remove_by_str(key.as_str());
+            insert_buf.insert(container_id, container.to_item());
+            Ok(true)
+        }
+        FoundItem::FoundSubItem(FoundSubItem { value: None, .. }) => Ok(false),
+        _ => Err(DBError::new("Unset operator can remove only map keys")),
+    }
+}
+
+/// Returns the vector stored by the found item together with its own link
+fn get_vector(
+    storage: &Storage,
+    found_item: &FoundItem,
+    insert_buf: &InsertBuffer,
+) -> Result<Option<(Link, StorageVector)>, DBError> {
+    let link = match found_item {
+        FoundItem::FoundRootItem(o) => o.id.clone(),
+        FoundItem::FoundSubItem(o) => {
+            let stored = match &o.container_value {
+                Item::Map(MapItem::StorageMap(m)) => m.get_by_str(o.key.as_str())?,
+                Item::Vector(VectorItem::StorageVector(v)) => v.get_by_str(o.key.as_str())?,
+                _ => None,
+            };
+            match stored {
+                Some(l) => l.to_link()?,
+                None => return Ok(None),
+            }
+        }
+    };
+    match storage.resolve_link(&link, insert_buf)? {
+        (vector_link, Item::Vector(VectorItem::StorageVector(v))) => Ok(Some((vector_link, v))),
+        _ => Err(DBError::new("Array update operators support vectors only")),
+    }
+}
+
+fn new_vector(value: Item) -> Result<Item, DBError> {
+    let mut vector = VectorItem::new(STORAGE_VECTOR.to_string())?;
+    vector.push(value)?;
+    Ok(Item::from(vector))
+}
+
+fn pull_matches(
+    storage: &Storage,
+    element: &Item,
+    expr: &Item,
+    insert_buf: &InsertBuffer,
+) -> Result<bool, DBError> {
+    match expr {
+        Item::Primitive(_)
+        | Item::Map(MapItem::StorageMap(_))
+        | Item::Vector(VectorItem::StorageVector(_)) => {
+            Ok(storage.fetch(element, insert_buf)? == *expr)
+        }
+        _ => {
+            // find expressions are applied to the element as to a separate object
+            let (element_id, _) = storage.resolve_link(&element.to_link()?, insert_buf)?;
+            Ok(compare(expr, &element_id, storage, insert_buf)? == Res::True)
+        }
+    }
+}
+
+/// Changes the vector in place: only the container and the added elements are written.
+/// Push and add_to_set create missing vectors, pull and pop skip them.
+fn manage_array_operator(
     op: &MapItem,
     storage: &Storage,
     insert_buf: &mut InsertBuffer,
     val: Item,
     found_item: FoundItem,
 ) -> Result<bool, DBError> {
+    let (vector_link, mut vector) = match get_vector(storage, &found_item, insert_buf)? {
+        Some(o) => o,
+        None => {
+            return match op {
+                MapItem::PushOperator(_) | MapItem::AddToSetOperator(_) => {
+                    update_item(storage, found_item, insert_buf, new_vector(val)?)
+                }
+                _ => Ok(false),
+            }
+        }
+    };
     match op {
-        MapItem::SetOperator(_) => {
-            update_item(storage, found_item, insert_buf, val.clone())?;
-        }
-        MapItem::IncOperator(_) => match &found_item.get_value() {
-            Some(Item::Primitive(Primitive::NumberPrimitive(found_val))) => match val {
-                Item::Primitive(Primitive::NumberPrimitive(adding_val)) => {
-                    let inserted_value =
-                        Item::Primitive(Primitive::NumberPrimitive(found_val.add(&adding_val)));
-                    update_item(storage, found_item, insert_buf, inserted_value)?;
+        MapItem::PushOperator(_) => {
+            vector.push(storage.insert_item(
+                INTERNAL_COLLECTION_NAME.to_string(),
+                insert_buf,
+                val,
+            )?)?;
+        }
+        MapItem::AddToSetOperator(_) => {
+            for element in &vector.items {
+                if storage.fetch(element, insert_buf)? == val {
+                    return Ok(false);
+                }
+            }
+            vector.push(storage.insert_item(
+                INTERNAL_COLLECTION_NAME.to_string(),
+                insert_buf,
+                val,
+            )?)?;
+        }
+        MapItem::PullOperator(_) => {
+            let size = vector.items.len();
+            let mut kept: Vec<Item> = vec![];
+            for element in vector.items {
+                if !pull_matches(storage, &element, &val, insert_buf)? {
+                    kept.push(element);
+                }
+            }
+            if kept.len() == size {
+                return Ok(false);
+            }
+            vector.items = kept;
+        }
+        MapItem::PopOperator(_) => {
+            if vector.items.is_empty() {
+                return Ok(false);
+            }
+            match val {
+                Item::Primitive(Primitive::NumberPrimitive(n)) if n.get_value() < 0.0 => {
+                    vector.items.remove(0);
                 }
                 _ => {
-                    return Err(DBError::new("Inc operator supports numbers only"));
+                    vector.items.pop();
                 }
-            },
-            _ => {}
-        },
+            }
+        }
         _ => {
             return Err(DBError::new("Unsupported update operation"));
         }
     }
-
+    insert_buf.insert(vector_link, vector.to_item());
     Ok(true)
 }
 
+fn insert_at(
+    k: &Primitive,
+    val: &Item,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &mut InsertBuffer,
+    create_paths: bool,
+) -> Result<bool, DBError> {
+    let (vector_path, index) = match k {
+        Primitive::PathToValue(path) => get_position(path).ok_or(DBError::new(
+            "Insert at operator keys must be paths to vector positions",
+        ))?,
+        _ => {
+            return Err(DBError::new(
+                "Insert at operator keys must be paths to vector positions",
+            ))
+        }
+    };
+    let vector_key = match vector_path {
+        Some(path) => Primitive::PathToValue(path),
+        None => Primitive::RootPrimitive(RootPrimitive),
+    };
+    let found_item = match find_or_create(&vector_key, id, storage, insert_buf, create_paths)? {
+        Some(o) => o,
+        None => return Ok(false),
+    };
+    match get_vector(storage, &found_item, insert_buf)? {
+        Some((vector_link, mut vector)) => {
+            if index > vector.items.len() {
+                return Err(DBError::new("Vector index does not exist"));
+            }
+            let element = storage.insert_item(
+                INTERNAL_COLLECTION_NAME.to_string(),
+                insert_buf,
+                val.clone(),
+            )?;
+            vector.items.insert(index, element);
+            insert_buf.insert(vector_link, vector.to_item());
+            Ok(true)
+        }
+        None => {
+            if index > 0 {
+                return Err(DBError::new("Vector index does not exist"));
+            }
+            update_item(storage, found_item, insert_buf, new_vector(val.clone())?)
+        }
+    }
+}
+
+/// Applies the operator to the found value.
+/// Missing values are created by all the operators except unset, pull and pop:
+/// inc and min/max take the given value, mul takes zero. Min/max replace nulls too.
+fn manage_operator(
+    op: &MapItem,
+    storage: &Storage,
+    insert_buf: &mut InsertBuffer,
+    val: Item,
+    found_item: FoundItem,
+) -> Result<bool, DBError> {
+    let inserted_value = match op {
+        MapItem::SetOperator(_) => val,
+        MapItem::IncOperator(_) => match val {
+            Item::Primitive(Primitive::NumberPrimitive(adding_val)) => {
+                match numeric_value(&found_item, "Inc")? {
+                    Some(found_val) => {
+                        Item::from(Primitive::NumberPrimitive(found_val.add(&adding_val)))
+                    }
+                    None => Item::from(Primitive::NumberPrimitive(adding_val)),
+                }
+            }
+            _ => {
+                return Err(DBError::new("Inc operator supports numbers only"));
+            }
+        },
+        MapItem::MulOperator(_) => match val {
+            Item::Primitive(Primitive::NumberPrimitive(multiplier)) => {
+                match numeric_value(&found_item, "Mul")? {
+                    Some(found_val) => {
+                        Item::from(Primitive::NumberPrimitive(found_val.mul(&multiplier)))
+                    }
+                    None => Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(0.0))),
+                }
+            }
+            _ => {
+                return Err(DBError::new("Mul operator supports numbers only"));
+            }
+        },
+        MapItem::MinOperator(_) | MapItem::MaxOperator(_) => {
+            let is_min = matches!(op, MapItem::MinOperator(_));
+            match (found_item.get_value(), &val) {
+                (None, _) | (Some(Item::Primitive(Primitive::NullPrimitive(_))), _) => val,
+                (Some(Item::Primitive(found_val)), Item::Primitive(new_val)) => {
+                    if found_val.get_prefix() != new_val.get_prefix() {
+                        return Err(DBError::new(
+                            "Min and max operators support values of the same type",
+                        ));
+                    }
+                    if (is_min && new_val < &found_val) || (!is_min && new_val > &found_val) {
+                        val
+                    } else {
+                        return Ok(false);
+                    }
+                }
+                _ => {
+                    return Err(DBError::new(
+                        "Min and max operators support primitives only",
+                    ));
+                }
+            }
+        }
+        MapItem::NowOperator(_) => Item::from(Primitive::UTSPrimitive(UTSPrimitive::now()?)),
+        MapItem::UnsetOperator(_) => return unset(found_item, insert_buf),
+        MapItem::PushOperator(_)
+        | MapItem::PullOperator(_)
+        | MapItem::AddToSetOperator(_)
+        | MapItem::PopOperator(_) => {
+            return manage_array_operator(op, storage, insert_buf, val, found_item)
+        }
+        _ => {
+            return Err(DBError::new("Unsupported update operation"));
+        }
+    };
+    update_item(storage, found_item, insert_buf, inserted_value)
+}
+
 fn get_value(
     pr: &Primitive,
     id: &Link,
@@ -98,6 +359,9 @@ fn get_value(
 ) -> Result<Option<FoundItem>, DBError> {
     match pr {
         Primitive::PathToValue(path) => {
+            if path.is_wildcard() {
+                return Err(DBError::new("Wildcard paths can not be updated"));
+            }
             let found_item = storage.get_value_by_path(path.clone(), id.clone(), insert_buf)?;
             match found_item {
                 Some(mut i) => {
@@ -110,7 +374,7 @@ fn get_value(
             }
         }
         Primitive::RootPrimitive(_) => {
-            let item = storage.get_item_by_link(id, insert_buf, 0, None)?;
+            let item = storage.get_item_by_link(id, insert_buf, storage.fetch_depth, None)?;
             Ok(Some(FoundItem::FoundRootItem(FoundRootItem {
                 id: id.clone(),
                 value: item,
@@ -120,25 +384,158 @@ fn get_value(
     }
 }
 
+/// Creates the missing intermediate maps of the path
+fn create_path(
+    path: &PathToValue,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &mut InsertBuffer,
+) -> Result<(), DBError> {
+    let parts: Vec<&str> = path.value.split(PATH_SEPARATOR).collect();
+    for i in 1..parts.len() {
+        let sub_path = Primitive::PathToValue(PathToValue {
+            value: parts[..i].join(PATH_SEPARATOR),
+        });
+        match get_value(&sub_path, id, storage, insert_buf)? {
+            Some(FoundItem::FoundSubItem(
+                found @ FoundSubItem {
+                    container_value: Item::Map(_),
+                    value: None,
+                    ..
+                },
+            )) => {
+                let empty = StorageMap {
+                    values: HashMap::new(),
+                };
+                update_sub_item(storage, found, insert_buf, empty.to_item())?;
+            }
+            Some(FoundItem::FoundSubItem(FoundSubItem {
+                value: Some(Item::Map(_)) | Some(Item::Vector(_)),
+                ..
+            })) => {}
+            _ => {
+                return Err(DBError::new(
+                    format!("Path {} can not be created", path.value).as_str(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn find_or_create(
+    pr: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &mut InsertBuffer,
+    create_paths: bool,
+) -> Result<Option<FoundItem>, DBError> {
+    let found = get_value(pr, id, storage, insert_buf)?;
+    match (found, pr) {
+        (None, Primitive::PathToValue(path)) if create_paths => {
+            create_path(path, id, storage, insert_buf)?;
+            get_value(pr, id, storage, insert_buf)
+        }
+        (found, _) => Ok(found),
+    }
+}
+
+/// Moves the link stored by one path to another one, so the value itself is not copied
+fn rename(
+    from: &Primitive,
+    to: &Primitive,
+    id: &Link,
+    storage: &Storage,
+    insert_buf: &mut InsertBuffer,
+    create_paths: bool,
+) -> Result<bool, DBError> {
+    match get_value(from, id, storage, insert_buf)? {
+        Some(FoundItem::FoundSubItem(FoundSubItem {
+            container_value: Item::Map(_),
+            value: Some(_),
+            ..
+        })) => {}
+        Some(FoundItem::FoundSubItem(FoundSubItem { value: None, .. })) | None => return Ok(false),
+        _ => return Err(DBError::new("Rename operator can move only map keys")),
+    }
+    match find_or_create(to, id, storage, insert_buf, create_paths)? {
+        Some(FoundItem::FoundSubItem(FoundSubItem {
+            container_value: Item::Map(_),
+            ..
+        })) => {}
+        None => return Ok(false),
+        _ => return Err(DBError::new("Rename operator can move only map keys")),
+    }
+
+    let link = match get_value(from, id, storage, insert_buf)? {
+        Some(FoundItem::FoundSubItem(FoundSubItem {
+            container_id,
+            container_value: Item::Map(MapItem::StorageMap(mut container)),
+            key,
+            ..
+        })) => {
+            let link = container
+                .remove_by_str(key.as_str())
+                .ok_or(DBError::new("Rename internal error"))?;
+            insert_buf.insert(container_id, container.to_item());
+            link
+        }
+        _ => return Err(DBError::new("Rename internal error")),
+    };
+    match get_value(to, id, storage, insert_buf)? {
+        Some(FoundItem::FoundSubItem(FoundSubItem {
+            container_id,
+            container_value: Item::Map(MapItem::StorageMap(mut container)),
+            key,
+            ..
+        })) => {
+            container.replace_by_string(key, link)?;
+            insert_buf.insert(container_id, container.to_item());
+        }
+        _ => return Err(DBError::new("Rename internal error")),
+    }
+    Ok(true)
+}
+
 fn process(
     op: &MapItem,
     storage: &Storage,
     filter_buf: &FilterBuffer,
     insert_buf: &mut InsertBuffer,
 ) -> Result<HashSet<Link>, DBError> {
+    let items = op.get_items();
+    let create_paths = items.iter().any(|(k, v)| {
+        is_create_paths_option(k)
+            && matches!(v, Item::Primitive(Primitive::BoolPrimitive(b)) if b.get_value())
+    });
+
     // Process update
     let mut updated: bool;
     let mut result: HashSet<Link> = HashSet::new();
     for id in &filter_buf.ids {
         updated = false;
-        for (k, v) in op.get_items() {
-            let val = get_value(&k, id, storage, insert_buf)?;
-            match val {
-                Some(o) => {
-                    updated = true;
-                    manage_operator(op, storage, insert_buf, v, o)?;
+        for (k, v) in &items {
+            if is_create_paths_option(k) {
+                continue;
+            }
+            if let MapItem::RenameOperator(_) = op {
+                if let Item::Primitive(to) = v {
+                    updated |= rename(k, to, id, storage, insert_buf, create_paths)?;
+                }
+                continue;
+            }
+            if let MapItem::InsertAtOperator(_) = op {
+                updated |= insert_at(k, v, id, storage, insert_buf, create_paths)?;
+                continue;
+            }
+            let val = match op {
+                MapItem::UnsetOperator(_) | MapItem::PullOperator(_) | MapItem::PopOperator(_) => {
+                    get_value(k, id, storage, insert_buf)?
                 }
-                _ => {}
+                _ => find_or_create(k, id, storage, insert_buf, create_paths)?,
+            };
+            if let Some(o) = val {
+                updated |= manage_operator(op, storage, insert_buf, v.clone(), o)?;
             }
         }
         if updated {
@@ -151,19 +548,138 @@ fn process(
 pub(crate) fn update(
     storage: &Storage,
     query: &UpdateQuery,
-    mut insert_buf: &mut InsertBuffer,
+    insert_buf: &mut InsertBuffer,
     filter_buf: &FilterBuffer,
 ) -> Result<QueryResponse, DBError> {
     let mut result: HashSet<Link> = HashSet::new();
     for item in query.get_items() {
         match item {
             Item::Map(op) => {
-                result.extend(process(op, storage, &filter_buf, &mut insert_buf)?);
+                result.extend(process(op, storage, filter_buf, insert_buf)?);
             }
             _ => return Err(DBError::new("Unexpected update operator")),
         }
     }
+    // Rewriting the root record makes the journal bump the version of the object
+    for id in &result {
+        if !insert_buf.items.contains_key(id) {
+            if let Some(item) = storage.get_stored_item(id, insert_buf) {
+                insert_buf.insert(id.clone(), item);
+            }
+        }
+    }
     let meta = Meta::UpdateMeta(UpdateMeta::new(result.len()));
     let data = Item::from(VectorItem::ResponseIds(ResponseIds::from(result)));
     Ok(QueryResponse::new(data, meta, QueryStatus::Ready))
 }
+
+fn set_by_path(map: &mut StorageMap, path: &[&str], value: Item) -> Result<(), DBError> {
+    let key = StringPrimitive::from(path[0]);
+    if path.len() == 1 {
+        map.values.insert(key, value);
+        return Ok(());
+    }
+    let entry = map.values.entry(key).or_insert_with(|| {
+        StorageMap {
+            values: HashMap::new(),
+        }
+        .to_item()
+    });
+    match entry {
+        Item::Map(MapItem::StorageMap(o)) => set_by_path(o, &path[1..], value),
+        _ => Err(DBError::new("Upsert paths conflict")),
+    }
+}
+
+fn collect_equalities(item: &Item, values: &mut Vec<(Primitive, Item)>) {
+    match item {
+        Item::Map(MapItem::EqOperator(o)) => {
+            for (k, v) in o.get_values() {
+                values.push((k.clone(), Item::from(v.clone())));
+            }
+        }
+        Item::Vector(VectorItem::AndOperator(o)) => {
+            for i in o.get_items() {
+                collect_equalities(i, values);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Builds the inserted document from the equality predicates of the find queries
+/// and the values of the upsert operators.
+fn build_upserted(query: &UpsertQuery, finds: &[FindQuery]) -> Result<Item, DBError> {
+    let mut values: Vec<(Primitive, Item)> = vec![];
+    for find in finds {
+        for item in find.get_items() {
+            collect_equalities(item, &mut values);
+        }
+    }
+    for item in query.get_items() {
+        if let Item::Map(op) = item {
+            values.extend(
+                op.get_items()
+                    .into_iter()
+                    .filter(|(k, _)| !is_create_paths_option(k)),
+            );
+        }
+    }
+
+    let mut root: Option<Item> = None;
+    let mut map = StorageMap {
+        values: HashMap::new(),
+    };
+    for (k, v) in values {
+        match k {
+            Primitive::PathToValue(path) => {
+                if path.is_wildcard() {
+                    return Err(DBError::new("Wildcard paths can not be upserted"));
+                }
+                let parts: Vec<&str> = path.value.split(PATH_SEPARATOR).collect();
+                set_by_path(&mut map, &parts, v)?;
+            }
+            Primitive::RootPrimitive(_) => root = Some(v),
+            _ => return Err(DBError::new("Only paths and root can be upserted")),
+        }
+    }
+    match root {
+        Some(value) => {
+            if !map.values.is_empty() {
+                return Err(DBError::new("Upsert paths conflict"));
+            }
+            Ok(value)
+        }
+        None => Ok(map.to_item()),
+    }
+}
+
+pub(crate) fn upsert(
+    storage: &Storage,
+    collection_name: String,
+    query: &UpsertQuery,
+    finds: &[FindQuery],
+    insert_buf: &mut InsertBuffer,
+    filter_buf: &FilterBuffer,
+) -> Result<QueryResponse, DBError> {
+    if !filter_buf.ids.is_empty() {
+        let mut response = update(storage, &query.to_update(), insert_buf, filter_buf)?;
+        if let Meta::UpdateMeta(meta) = &response.meta {
+            response.meta = Meta::UpdateMeta(UpdateMeta::new_upsert(
+                meta.count.get_value() as usize,
+                false,
+            ));
+        }
+        return Ok(response);
+    }
+    let document = build_upserted(query, finds)?;
+    let id = storage.insert_item(collection_name, insert_buf, document)?;
+    let mut ids = ResponseIds::new("".to_string())?;
+    ids.push(id)?;
+    let meta = Meta::UpdateMeta(UpdateMeta::new_upsert(1, true));
+    Ok(QueryResponse::new(
+        Item::from(VectorItem::ResponseIds(ids)),
+        meta,
+        QueryStatus::Ready,
+    ))
+}
@@ -1,6 +1,6 @@
-use crate::constants::UPDATE_QUERY;
+use crate::constants::{UPDATE_QUERY, UPSERT_QUERY};
 use crate::query::operations::QueryOperation;
-use crate::{DBError, Item, TySONVector, VectorItem};
+use crate::{DBError, Item, MapItem, TySONVector, VectorItem};
 
 use crate::tyson::item::BaseTySONItemInterface;
 
@@ -53,3 +53,52 @@ impl UpdateQuery {
     //     Ok(Response { data })
     // }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpsertQuery {
+    pub(crate) items: Vec<Item>,
+}
+
+impl BaseTySONItemInterface for UpsertQuery {
+    fn get_prefix(&self) -> String {
+        UPSERT_QUERY.to_string()
+    }
+}
+
+impl TySONVector for UpsertQuery {
+    fn new(_: String) -> Result<Self, DBError> {
+        Ok(Self { items: vec![] })
+    }
+
+    fn push(&mut self, item: Item) -> Result<bool, DBError> {
+        match &item {
+            Item::Map(MapItem::SetOperator(_)) | Item::Map(MapItem::IncOperator(_)) => {
+                self.items.push(item);
+                Ok(true)
+            }
+            _ => Err(DBError::new(
+                "Upsert query supports only set and inc operators",
+            )),
+        }
+    }
+
+    fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    fn to_item(self) -> Item {
+        Item::Vector(VectorItem::UpsertQuery(self))
+    }
+}
+
+impl UpsertQuery {
+    pub fn next_available(&self) -> Vec<QueryOperation> {
+        vec![]
+    }
+
+    pub(crate) fn to_update(&self) -> UpdateQuery {
+        UpdateQuery {
+            items: self.items.clone(),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::constants::{DELETE_META, FIND_META, GET_META, INSERT_META, UPDATE_META};
+use crate::constants::{DELETE_META, FIND_META, GET_META, INSERT_META, NULL, UPDATE_META};
+use crate::data_types::primitives::bool::BoolPrimitive;
 use crate::data_types::primitives::number::NumberPrimitive;
+use crate::data_types::primitives::string::StringPrimitive;
 use crate::TySONPrimitive;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -56,34 +58,77 @@ impl DeleteMeta {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct UpdateMeta {
     pub count: NumberPrimitive,
+    pub upserted: Option<BoolPrimitive>,
 }
 
 impl UpdateMeta {
     pub fn new(count: usize) -> Self {
         Self {
             count: NumberPrimitive::from(count),
+            upserted: None,
+        }
+    }
+
+    pub fn new_upsert(count: usize, upserted: bool) -> Self {
+        let upserted = if upserted {
+            BoolPrimitive::create_true()
+        } else {
+            BoolPrimitive::create_false()
+        };
+        Self {
+            count: NumberPrimitive::from(count),
+            upserted: Some(upserted),
         }
     }
 
     pub fn serialize(&self) -> String {
-        format!("{}{{s|count|:{}}}", UPDATE_META, self.count.serialize())
+        match &self.upserted {
+            Some(upserted) => format!(
+                "{}{{s|count|:{},s|upserted|:{}}}",
+                UPDATE_META,
+                self.count.serialize(),
+                upserted.serialize()
+            ),
+            None => format!("{}{{s|count|:{}}}", UPDATE_META, self.count.serialize()),
+        }
     }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct FindMeta {
     pub count: NumberPrimitive,
+    pub cursor: Option<Option<StringPrimitive>>,
 }
 
 impl FindMeta {
     pub fn new(count: usize) -> Self {
         Self {
             count: NumberPrimitive::from(count),
+            cursor: None,
+        }
+    }
+
+    /// The cursor is null, when there are no more objects
+    pub fn new_cursor(count: usize, cursor: Option<String>) -> Self {
+        Self {
+            count: NumberPrimitive::from(count),
+            cursor: Some(cursor.map(|id| StringPrimitive::from(id.as_str()))),
         }
     }
 
     pub fn serialize(&self) -> String {
-        format!("{}{{s|count|:{}}}", FIND_META, self.count.serialize())
+        match &self.cursor {
+            Some(cursor) => format!(
+                "{}{{s|count|:{},s|cursor|:{}}}",
+                FIND_META,
+                self.count.serialize(),
+                match cursor {
+                    Some(id) => id.serialize(),
+                    None => NULL.to_string(),
+                }
+            ),
+            None => format!("{}{{s|count|:{}}}", FIND_META, self.count.serialize()),
+        }
     }
 }
 
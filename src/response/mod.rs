@@ -61,17 +61,29 @@ impl OkTransactionResponse {
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ErrorTransactionResponse {
     error: String,
+    step: Option<usize>,
+    query: Option<usize>,
 }
 
 impl From<DBError> for ErrorTransactionResponse {
     fn from(e: DBError) -> Self {
-        Self { error: e.msg }
+        Self {
+            error: e.msg,
+            step: e.step,
+            query: e.query,
+        }
     }
 }
 
 impl ErrorTransactionResponse {
     pub fn serialize(&self) -> String {
-        format!("{}:error|{}|", TRANSACTION_RESPONSE, self.error)
+        match (self.step, self.query) {
+            (Some(step), Some(query)) => format!(
+                "{}:error{{s|error|:s|{}|,s|step|:n|{}|,s|query|:n|{}|,}}",
+                TRANSACTION_RESPONSE, self.error, step, query
+            ),
+            _ => format!("{}:error|{}|", TRANSACTION_RESPONSE, self.error),
+        }
     }
 }
 
@@ -1,4 +1,6 @@
-use crate::constants::INTERNAL_COLLECTION_NAME;
+use crate::constants::{
+    INTERNAL_COLLECTION_NAME, POLICIES_COLLECTION_NAME, PROCEDURES_COLLECTION_NAME,
+};
 use std::collections::HashMap;
 use std::fs;
 use std::fs::{read_to_string, File};
@@ -11,11 +13,14 @@ use crate::data_types::primitives::Primitive;
 use crate::DBError;
 
 use crate::tyson::de::Desereilize;
+use crate::tyson::primitive::TySONPrimitive;
 
 #[derive(Debug)]
 pub struct Collection {
     pub name: String,
     pub(crate) values: HashMap<Link, Item>,
+    /// Number of the journal records of every root object
+    pub(crate) versions: HashMap<Link, usize>,
 }
 
 impl Desereilize for Collection {
@@ -27,6 +32,7 @@ impl Desereilize for Collection {
         Self {
             name,
             values: HashMap::new(),
+            versions: HashMap::new(),
         }
     }
 
@@ -34,9 +40,11 @@ impl Desereilize for Collection {
         match data.0 {
             Primitive::Link(o) => match data.1 {
                 Item::Primitive(Primitive::DeletedPrimitive(_)) => {
+                    self.bump_version(&o);
                     self.values.remove(&o);
                 }
                 _ => {
+                    self.bump_version(&o);
                     self.values.insert(o, data.1);
                 }
             },
@@ -45,11 +53,20 @@ impl Desereilize for Collection {
 
         Ok(true)
     }
+
+    /// The journal keys are links, whatever the collection is named
+    fn push_pair(&mut self, prefix: String, value: String, data: Item) -> Result<bool, DBError> {
+        self.push((Primitive::Link(Link::new(prefix, value)?), data))
+    }
 }
 
 impl Collection {
     pub(crate) fn new(name: String, wh_path: String) -> Result<Self, DBError> {
-        if !name.starts_with("_") || name == INTERNAL_COLLECTION_NAME.to_string() {
+        if !name.starts_with("_")
+            || name == INTERNAL_COLLECTION_NAME
+            || name == POLICIES_COLLECTION_NAME
+            || name == PROCEDURES_COLLECTION_NAME
+        {
             let file_path = format!("{}/{}.tyson", wh_path, name);
             let is_exists = std::path::Path::new(file_path.as_str()).exists();
             if is_exists {
@@ -57,10 +74,7 @@ impl Collection {
                 Ok(Self::deserialize(name, data)?)
             } else {
                 File::create(file_path.as_str())?;
-                Ok(Self {
-                    name,
-                    values: HashMap::new(),
-                })
+                Ok(<Self as Desereilize>::new(name))
             }
         } else {
             Err(DBError::new(
@@ -69,6 +83,17 @@ impl Collection {
         }
     }
 
+    /// Versions are kept for the root objects only
+    pub(crate) fn bump_version(&mut self, id: &Link) {
+        if self.name != INTERNAL_COLLECTION_NAME {
+            *self.versions.entry(id.clone()).or_default() += 1;
+        }
+    }
+
+    pub(crate) fn get_version(&self, id: &Link) -> usize {
+        self.versions.get(id).copied().unwrap_or(0)
+    }
+
     pub(crate) fn get_path(&self, wh_path: String) -> String {
         format!("{}/{}.tyson", wh_path, self.name)
     }
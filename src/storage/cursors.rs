@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::constants::{CURSORS_LIMIT, CURSOR_IDLE_TIMEOUT_SECS};
+use crate::query::project::query::ProjectQuery;
+use crate::{DBError, Link};
+
+/// Snapshot of the ids left after the returned batches.
+/// The objects are fetched by the `more` steps, so they contain the latest changes.
+#[derive(Debug, Clone)]
+pub struct Cursor {
+    ids: Vec<Link>,
+    size: usize,
+    pub(crate) projection: Option<ProjectQuery>,
+    pub(crate) depth: usize,
+    last_used: Instant,
+}
+
+impl Cursor {
+    pub(crate) fn new(
+        ids: Vec<Link>,
+        size: usize,
+        projection: Option<ProjectQuery>,
+        depth: usize,
+    ) -> Self {
+        Self {
+            ids,
+            size,
+            projection,
+            depth,
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Takes the ids of the next batch.
+    /// The ids, which do not exist anymore, are skipped.
+    pub(crate) fn next_batch<F>(&mut self, exists: F) -> Vec<Link>
+    where
+        F: Fn(&Link) -> bool,
+    {
+        let mut batch: Vec<Link> = vec![];
+        let mut taken = 0;
+        for id in &self.ids {
+            if batch.len() == self.size {
+                break;
+            }
+            taken += 1;
+            if exists(id) {
+                batch.push(id.clone());
+            }
+        }
+        self.ids.drain(..taken);
+        self.last_used = Instant::now();
+        batch
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+/// Open cursors by their ids.
+/// They live in memory and are closed after `CURSOR_IDLE_TIMEOUT_SECS` without the `more` steps.
+#[derive(Debug)]
+pub struct Cursors {
+    cursors: HashMap<String, Cursor>,
+}
+
+impl Cursors {
+    pub(crate) fn new() -> Self {
+        Self {
+            cursors: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn create_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    pub(crate) fn close_idle(&mut self) {
+        let timeout = Duration::from_secs(CURSOR_IDLE_TIMEOUT_SECS);
+        self.cursors.retain(|_, c| c.last_used.elapsed() < timeout);
+    }
+
+    /// Only the new cursors of the transaction are counted, the changed ones are open already
+    pub(crate) fn check_limit(
+        &self,
+        changes: &HashMap<String, Option<Cursor>>,
+    ) -> Result<(), DBError> {
+        let opened = changes
+            .iter()
+            .filter(|(id, c)| c.is_some() && !self.cursors.contains_key(*id))
+            .count();
+        if self.cursors.len() + opened > CURSORS_LIMIT {
+            return Err(DBError::new("Too many open cursors"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, id: &str) -> Option<&Cursor> {
+        self.cursors.get(id)
+    }
+
+    /// Applies the cursor changes of a committed transaction. None closes the cursor.
+    pub(crate) fn apply(&mut self, changes: HashMap<String, Option<Cursor>>) {
+        for (id, cursor) in changes {
+            match cursor {
+                Some(c) => {
+                    self.cursors.insert(id, c);
+                }
+                None => {
+                    self.cursors.remove(&id);
+                }
+            }
+        }
+    }
+}
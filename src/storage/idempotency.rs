@@ -0,0 +1,35 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::constants::IDEMPOTENCY_KEYS_LIMIT;
+
+/// Responses of the committed transactions by their idempotency keys.
+/// It lives in memory and keeps only the latest `IDEMPOTENCY_KEYS_LIMIT` keys.
+#[derive(Debug)]
+pub struct IdempotencyCache {
+    responses: HashMap<String, String>,
+    keys: VecDeque<String>,
+}
+
+impl IdempotencyCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            responses: HashMap::new(),
+            keys: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&String> {
+        self.responses.get(key)
+    }
+
+    pub(crate) fn insert(&mut self, key: String, response: String) {
+        if self.responses.insert(key.clone(), response).is_none() {
+            self.keys.push_back(key);
+        }
+        while self.keys.len() > IDEMPOTENCY_KEYS_LIMIT {
+            if let Some(oldest) = self.keys.pop_front() {
+                self.responses.remove(&oldest);
+            }
+        }
+    }
+}
@@ -1,33 +1,53 @@
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::Write;
 
 use crate::constants::{
-    DELETED, FETCH_DEPTH_LIMIT, INTERNAL_COLLECTION_NAME, NULL, ROOT, STORAGE_MAP, STORAGE_VECTOR,
+    FETCH_DEPTH_LIMIT, INTERNAL_COLLECTION_NAME, LAST_INDEX_PATH, NULL, PATH_SEPARATOR,
+    POLICIES_COLLECTION_NAME, PROCEDURES_COLLECTION_NAME, RESERVED_COLLECTION_NAMES, ROOT,
+    STORAGE_MAP, STORAGE_VECTOR, WILDCARD_PATH,
 };
 use crate::data_types::modifier::ModifierItem;
 use crate::data_types::primitives::path::PathToValue;
 use crate::errors::DBError;
+use crate::query::assert::processor::assert;
+use crate::query::count::processor::count;
+use crate::query::cursor::processor::{cursor, get_batch_size};
+use crate::query::delete::processor::delete;
+use crate::query::depth::processor::depth;
+use crate::query::distinct::processor::distinct;
 use crate::query::find::processor::find;
+use crate::query::find::query::FindQuery;
 use crate::query::get::processor::get;
-use crate::query::insert::processor::insert;
+use crate::query::group::processor::group;
+use crate::query::if_version::processor::if_version;
+use crate::query::insert::processor::{insert, insert_with_ids};
 use crate::query::limit::processor::limit;
 use crate::query::offset::processor::offset;
+use crate::query::on_delete::processor::on_delete;
 use crate::query::operations::QueryOperation;
 use crate::query::project::processor::resolve;
 use crate::query::project::query::ProjectQuery;
+use crate::query::referenced_by::processor::referenced_by;
+use crate::query::replace::processor::replace;
+use crate::query::shortest_path::processor::shortest_path;
 use crate::query::sort::processor::sort;
-use crate::query::update::operators::set::SetOperator;
-use crate::query::update::processor::update;
-use crate::query::update::query::UpdateQuery;
-use crate::response::meta::{DeleteMeta, FindMeta, Meta};
+use crate::query::traverse::processor::traverse;
+use crate::query::update::processor::{update, upsert};
+use crate::response::meta::{FindMeta, Meta};
 use crate::response::objects::ResponseObjects;
 use crate::response::{
     ErrorTransactionResponse, OkTransactionResponse, QueryResponse, QueryStatus,
 };
 use crate::storage::buffer::{FilterBuffer, InsertBuffer};
 use crate::storage::collection::Collection;
+use crate::storage::cursors::{Cursor, Cursors};
+use crate::storage::idempotency::IdempotencyCache;
+use crate::storage::prepared::{PreparedQueries, PreparedQuery};
+use crate::storage::procedures;
+use crate::storage::references::{links_to, ReferenceIndex};
+use crate::storage::transaction::{TransactionItem, TransactionStep};
 use crate::tyson::item::BaseTySONItemInterface;
 use crate::{
     Desereilize, Item, Link, MapItem, Primitive, Transaction, TySONMap, TySONPrimitive,
@@ -72,10 +92,39 @@ pub struct FoundRootItem {
     pub value: Item,
 }
 
+/// The service collections are changed only by their own transaction items.
+/// They can be dropped as a whole, which resets the policies or the procedures.
+fn check_collection_access(step: &TransactionStep) -> Result<(), DBError> {
+    let name = step.collection_name.as_str();
+    if name != POLICIES_COLLECTION_NAME && name != PROCEDURES_COLLECTION_NAME {
+        return Ok(());
+    }
+    match step.query_set.items.first() {
+        Some(Item::Primitive(Primitive::DeleteQuery(_))) => Ok(()),
+        _ => Err(DBError::new(
+            format!("Collection {} is reserved", name).as_str(),
+        )),
+    }
+}
+
+/// State of the transaction, restored by the rollback to the savepoint
+struct Savepoint {
+    name: String,
+    insert_buf: InsertBuffer,
+    cursor_changes: HashMap<String, Option<Cursor>>,
+    responses: usize,
+    results: Vec<Option<QueryResponse>>,
+}
+
 #[derive(Debug)]
 pub struct Storage {
     pub(crate) warehouse: HashMap<String, Collection>,
     wh_path: String,
+    references: ReferenceIndex,
+    idempotency: IdempotencyCache,
+    prepared: PreparedQueries,
+    cursors: Cursors,
+    pub(crate) fetch_depth: usize,
 }
 
 impl Storage {
@@ -89,32 +138,188 @@ impl Storage {
             let collection = Collection::new(collection_name.clone(), wh_path.clone())?;
             warehouse.insert(collection_name.clone(), collection);
         }
-        Ok(Self { warehouse, wh_path })
+        let mut references = ReferenceIndex::new();
+        for collection in warehouse.values() {
+            for (link, item) in &collection.values {
+                references.add(link, item);
+            }
+        }
+        Ok(Self {
+            warehouse,
+            wh_path,
+            references,
+            idempotency: IdempotencyCache::new(),
+            prepared: PreparedQueries::new(),
+            cursors: Cursors::new(),
+            fetch_depth: FETCH_DEPTH_LIMIT as usize,
+        })
+    }
+
+    /// Links to the collections named as primitives can not be parsed,
+    /// so a new collection can not take such a name. The existing ones are kept as is.
+    fn check_collection_name(&self, name: &str) -> Result<(), DBError> {
+        if RESERVED_COLLECTION_NAMES.contains(&name) && !self.warehouse.contains_key(name) {
+            return Err(DBError::new(
+                format!("Invalid collection name: {}", name).as_str(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sets the default depth of the link expansion
+    pub fn with_fetch_depth(mut self, depth: usize) -> Self {
+        self.fetch_depth = depth.min(FETCH_DEPTH_LIMIT as usize);
+        self
     }
 
     pub fn run(&mut self, data: String) -> String {
         return match self.run_transaction(data) {
-            Ok(response) => response.serialize(),
+            Ok(response) => response,
             Err(e) => ErrorTransactionResponse::from(e).serialize(),
         };
     }
 
-    fn run_transaction(&mut self, data: String) -> Result<OkTransactionResponse, DBError> {
+    fn run_transaction(&mut self, data: String) -> Result<String, DBError> {
         let transaction = Transaction::deserialize("".to_string(), data)?;
+        if let Some(key) = &transaction.idempotency_key {
+            if let Some(response) = self.idempotency.get(key) {
+                return Ok(response.clone());
+            }
+        }
+        self.prepared.check_limit(&transaction.prepared)?;
+        self.cursors.close_idle();
+        // Every item keeps the index of the transaction item it comes from
+        let mut steps: VecDeque<(usize, TransactionItem)> = self
+            .prepared
+            .expand(transaction.steps, &transaction.prepared)?
+            .into();
 
         let mut transaction_response: OkTransactionResponse = OkTransactionResponse::new();
         // let mut bufs: Vec<InsertBuffer> = vec![];
         let mut insert_buf: InsertBuffer = InsertBuffer::new();
-        let mut projection: Option<ProjectQuery> = None;
+        // Cursor changes are applied only when the transaction is committed
+        let mut cursor_changes: HashMap<String, Option<Cursor>> = HashMap::new();
+        let mut savepoints: Vec<Savepoint> = vec![];
+        // Results of the transaction items by their indexes, see StepResult
+        let mut results: Vec<Option<QueryResponse>> = vec![];
 
-        for query_set in transaction.steps {
+        while let Some((step, item)) = steps.pop_front() {
+            if results.len() <= step {
+                results.resize(step + 1, None);
+            }
+            let mut query_set = match item {
+                TransactionItem::Step(o) => {
+                    check_collection_access(&o)?;
+                    self.check_collection_name(o.collection_name.as_str())?;
+                    o
+                }
+                TransactionItem::Savepoint(name) => {
+                    savepoints.push(Savepoint {
+                        name,
+                        insert_buf: insert_buf.clone(),
+                        cursor_changes: cursor_changes.clone(),
+                        responses: transaction_response.responses.len(),
+                        results: results.clone(),
+                    });
+                    continue;
+                }
+                TransactionItem::RollbackTo(name) => {
+                    // The savepoint stays available, the later ones are released.
+                    // The responses of the rolled back steps are discarded too.
+                    match savepoints.iter().rposition(|o| o.name == name) {
+                        Some(position) => {
+                            savepoints.truncate(position + 1);
+                            let savepoint = &savepoints[position];
+                            insert_buf = savepoint.insert_buf.clone();
+                            cursor_changes = savepoint.cursor_changes.clone();
+                            transaction_response.responses.truncate(savepoint.responses);
+                            results = savepoint.results.clone();
+                            results.resize(step + 1, None);
+                        }
+                        None => {
+                            return Err(DBError::new(
+                                format!("Savepoint {} does not exist", name).as_str(),
+                            ))
+                        }
+                    }
+                    continue;
+                }
+                TransactionItem::Execute(name, _) => {
+                    return Err(DBError::new(
+                        format!("Prepared query {} is not expanded", name).as_str(),
+                    ))
+                }
+                TransactionItem::Procedure(procedure, template) => {
+                    procedures::define(self, &procedure, template, &mut insert_buf)?;
+                    continue;
+                }
+                TransactionItem::Call(call, values) => {
+                    // The steps of the procedure run in place of the call
+                    let template = procedures::load(self, &call, &insert_buf)?;
+                    let query = PreparedQuery::new(Transaction::from_template(template)?)?;
+                    for item in query.bind(&values)?.into_iter().rev() {
+                        steps.push_front((step, item));
+                    }
+                    continue;
+                }
+                TransactionItem::More(id) => {
+                    let mut opened = match cursor_changes.get(&id) {
+                        Some(o) => o.clone(),
+                        None => self.cursors.get(&id).cloned(),
+                    }
+                    .ok_or_else(|| {
+                        DBError::new(format!("Cursor {} does not exist", id).as_str())
+                    })?;
+                    let mut filter_buf: FilterBuffer = FilterBuffer::new();
+                    filter_buf.update(opened.next_batch(|link| self.exists(link, &insert_buf)));
+                    let data = self.fetch_found_ids(
+                        &filter_buf,
+                        &insert_buf,
+                        opened.projection.as_ref(),
+                        opened.depth,
+                    )?;
+                    let next = if opened.is_exhausted() {
+                        None
+                    } else {
+                        Some(id.clone())
+                    };
+                    let meta = Meta::FindMeta(FindMeta::new_cursor(filter_buf.ids.len(), next));
+                    let response = QueryResponse::new(data, meta, QueryStatus::Ready);
+                    results[step] = Some(response.clone());
+                    transaction_response.add_response(response);
+                    // The exhausted cursor is closed
+                    if opened.is_exhausted() {
+                        cursor_changes.insert(id, None);
+                    } else {
+                        cursor_changes.insert(id, Some(opened));
+                    }
+                    continue;
+                }
+                TransactionItem::Close(id) => {
+                    // Closing of an unknown cursor is fine, it could be closed by the timeout
+                    cursor_changes.insert(id, None);
+                    continue;
+                }
+            };
+            query_set.resolve_step_results(&results)?;
             let mut filter_buf: FilterBuffer = FilterBuffer::new();
+            let mut projection: Option<ProjectQuery> = None;
+            let mut fetch_depth: usize = self.fetch_depth;
+            let mut opened_cursor: Option<(Option<String>, Vec<Link>, usize)> = None;
             let mut next_available: Vec<QueryOperation> = vec![
                 QueryOperation::InsertOperation,
                 QueryOperation::FindOperation,
                 QueryOperation::GetOperation,
                 QueryOperation::DeleteOperation,
+                QueryOperation::ReferencedByOperation,
+                QueryOperation::OnDeleteOperation,
+                QueryOperation::CountOperation,
+                QueryOperation::DistinctOperation,
+                QueryOperation::AssertOperation,
+                QueryOperation::TraverseOperation,
+                QueryOperation::ShortestPathOperation,
             ];
+            let mut find_queries: Vec<FindQuery> = vec![];
             let collection_name = query_set.collection_name.clone();
             let mut iteration = 0;
             let query_set_size = query_set.query_set.items.len() as i32;
@@ -125,7 +330,7 @@ impl Storage {
                         if next_available.contains(&QueryOperation::InsertOperation) {
                             next_available = o.next_available();
                             Some(insert(
-                                &self,
+                                self,
                                 collection_name.clone(),
                                 &o.items,
                                 &mut insert_buf,
@@ -134,12 +339,26 @@ impl Storage {
                             return Err(DBError::new("Insert query is unavailable"));
                         }
                     }
+                    Item::Map(MapItem::InsertWithIdsQuery(o)) => {
+                        if next_available.contains(&QueryOperation::InsertOperation) {
+                            next_available = o.next_available();
+                            Some(insert_with_ids(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &mut insert_buf,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Insert query is unavailable"));
+                        }
+                    }
                     Item::Vector(VectorItem::FindQuery(o)) => {
                         if next_available.contains(&QueryOperation::FindOperation) {
                             next_available = o.next_available();
                             let is_first: bool = if iteration == 1 { true } else { false };
+                            find_queries.push(o.clone());
                             Some(find(
-                                &self,
+                                self,
                                 collection_name.clone(),
                                 &o,
                                 &mut filter_buf,
@@ -153,7 +372,13 @@ impl Storage {
                     Item::Vector(VectorItem::GetQuery(o)) => {
                         if next_available.contains(&QueryOperation::GetOperation) {
                             next_available = o.next_available();
-                            Some(get(&self, collection_name.clone(), &o, &mut filter_buf)?)
+                            Some(get(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &mut filter_buf,
+                                &insert_buf,
+                            )?)
                         } else {
                             return Err(DBError::new("Get query is unavailable"));
                         }
@@ -161,52 +386,126 @@ impl Storage {
                     Item::Vector(VectorItem::UpdateQuery(o)) => {
                         if next_available.contains(&QueryOperation::UpdateOperation) {
                             next_available = o.next_available();
-                            Some(update(&self, &o, &mut insert_buf, &filter_buf)?)
+                            Some(update(self, &o, &mut insert_buf, &filter_buf)?)
                         } else {
                             return Err(DBError::new("Update query is unavailable"));
                         }
                     }
+                    Item::Vector(VectorItem::UpsertQuery(o)) => {
+                        if next_available.contains(&QueryOperation::UpsertOperation) {
+                            next_available = o.next_available();
+                            Some(upsert(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &find_queries,
+                                &mut insert_buf,
+                                &filter_buf,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Upsert query is unavailable"));
+                        }
+                    }
                     Item::Vector(VectorItem::SortQuery(o)) => {
                         if next_available.contains(&QueryOperation::SortOperation) {
                             next_available = o.next_available();
-                            Some(sort(&o, &self, &mut filter_buf, &insert_buf)?)
+                            Some(sort(&o, self, &mut filter_buf, &insert_buf)?)
                         } else {
                             return Err(DBError::new("Sort query is unavailable"));
                         }
                     }
                     Item::Primitive(Primitive::DeleteQuery(o)) => {
-                        // TODO rework this
                         if next_available.contains(&QueryOperation::DeleteOperation) {
                             next_available = o.next_available();
-                            let delete_res: QueryResponse;
-                            if iteration == 1 {
-                                insert_buf.add_collection_to_drop(collection_name.clone());
-                                let data = Item::Primitive(Primitive::new(
-                                    NULL.to_string(),
-                                    "".to_string(),
-                                )?);
-                                let meta = Meta::DeleteMeta(DeleteMeta::new(0 as usize));
-                                delete_res = QueryResponse::new(data, meta, QueryStatus::Ready);
-                            } else {
-                                let set_operator = Item::Map(MapItem::SetOperator(SetOperator {
-                                    values: vec![(
-                                        Primitive::new(ROOT.to_string(), "".to_string())?,
-                                        Item::Primitive(Primitive::new(
-                                            DELETED.to_string(),
-                                            "".to_string(),
-                                        )?),
-                                    )],
-                                }));
-                                let query = UpdateQuery {
-                                    items: vec![set_operator],
-                                };
-                                delete_res = update(self, &query, &mut insert_buf, &filter_buf)?;
-                            }
-                            Some(delete_res)
+                            let is_first: bool = iteration == 1;
+                            Some(delete(
+                                self,
+                                collection_name.clone(),
+                                &filter_buf,
+                                &mut insert_buf,
+                                is_first,
+                            )?)
                         } else {
                             return Err(DBError::new("Delete query is unavailable"));
                         }
                     }
+                    Item::Primitive(Primitive::ReferencedByQuery(o)) => {
+                        if next_available.contains(&QueryOperation::ReferencedByOperation) {
+                            next_available = o.next_available();
+                            let is_first: bool = iteration == 1;
+                            Some(referenced_by(
+                                self,
+                                collection_name.clone(),
+                                &filter_buf,
+                                &insert_buf,
+                                is_first,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Referenced by query is unavailable"));
+                        }
+                    }
+                    Item::Primitive(Primitive::CountAccumulator(o)) => {
+                        if next_available.contains(&QueryOperation::CountOperation) {
+                            next_available = o.next_available();
+                            let is_first: bool = iteration == 1;
+                            Some(count(
+                                self,
+                                collection_name.clone(),
+                                &filter_buf,
+                                &insert_buf,
+                                is_first,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Count query is unavailable"));
+                        }
+                    }
+                    Item::Modifier(ModifierItem::ReplaceQuery(o)) => {
+                        if next_available.contains(&QueryOperation::ReplaceOperation) {
+                            next_available = o.next_available();
+                            Some(replace(self, &o, &filter_buf, &mut insert_buf)?)
+                        } else {
+                            return Err(DBError::new("Replace query is unavailable"));
+                        }
+                    }
+                    Item::Modifier(ModifierItem::IfVersionQuery(o)) => {
+                        if next_available.contains(&QueryOperation::IfVersionOperation) {
+                            next_available = o.next_available();
+                            Some(if_version(self, &o, &filter_buf, &insert_buf)?)
+                        } else {
+                            return Err(DBError::new("If version query is unavailable"));
+                        }
+                    }
+                    Item::Modifier(ModifierItem::AssertQuery(o)) => {
+                        if next_available.contains(&QueryOperation::AssertOperation) {
+                            Some(assert(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &filter_buf,
+                                &insert_buf,
+                                step,
+                                iteration,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Assert query is unavailable"));
+                        }
+                    }
+                    Item::Modifier(ModifierItem::DistinctQuery(o)) => {
+                        if next_available.contains(&QueryOperation::DistinctOperation) {
+                            next_available = o.next_available();
+                            let is_first: bool = iteration == 1;
+                            Some(distinct(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &filter_buf,
+                                &insert_buf,
+                                is_first,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Distinct query is unavailable"));
+                        }
+                    }
                     Item::Modifier(ModifierItem::LimitQuery(o)) => {
                         if next_available.contains(&QueryOperation::LimitOperation) {
                             next_available = o.next_available();
@@ -223,6 +522,84 @@ impl Storage {
                             return Err(DBError::new("Offset query is unavailable"));
                         }
                     }
+                    Item::Modifier(ModifierItem::CursorQuery(o)) => {
+                        if next_available.contains(&QueryOperation::CursorOperation) {
+                            next_available = o.next_available();
+                            let (response, rest) = cursor(&o, &mut filter_buf)?;
+                            let id = if rest.is_empty() {
+                                None
+                            } else {
+                                Some(Cursors::create_id())
+                            };
+                            opened_cursor = Some((id, rest, get_batch_size(&o)?));
+                            Some(response)
+                        } else {
+                            return Err(DBError::new("Cursor query is unavailable"));
+                        }
+                    }
+                    Item::Modifier(ModifierItem::DepthQuery(o)) => {
+                        if next_available.contains(&QueryOperation::DepthOperation) {
+                            next_available = o.next_available();
+                            let (response, value) = depth(&o, &filter_buf)?;
+                            fetch_depth = value;
+                            Some(response)
+                        } else {
+                            return Err(DBError::new("Depth query is unavailable"));
+                        }
+                    }
+                    Item::Map(MapItem::OnDeleteQuery(o)) => {
+                        if next_available.contains(&QueryOperation::OnDeleteOperation) {
+                            next_available = o.next_available();
+                            Some(on_delete(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &mut insert_buf,
+                            )?)
+                        } else {
+                            return Err(DBError::new("On delete query is unavailable"));
+                        }
+                    }
+                    Item::Map(MapItem::GroupQuery(o)) => {
+                        if next_available.contains(&QueryOperation::GroupOperation) {
+                            next_available = o.next_available();
+                            Some(group(self, &o, &filter_buf, &insert_buf)?)
+                        } else {
+                            return Err(DBError::new("Group query is unavailable"));
+                        }
+                    }
+                    Item::Map(MapItem::TraverseQuery(o)) => {
+                        if next_available.contains(&QueryOperation::TraverseOperation) {
+                            next_available = o.next_available();
+                            let is_first: bool = iteration == 1;
+                            Some(traverse(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &mut filter_buf,
+                                &insert_buf,
+                                is_first,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Traverse query is unavailable"));
+                        }
+                    }
+                    Item::Map(MapItem::ShortestPathQuery(o)) => {
+                        if next_available.contains(&QueryOperation::ShortestPathOperation) {
+                            next_available = o.next_available();
+                            let is_first: bool = iteration == 1;
+                            Some(shortest_path(
+                                self,
+                                collection_name.clone(),
+                                &o,
+                                &mut filter_buf,
+                                &insert_buf,
+                                is_first,
+                            )?)
+                        } else {
+                            return Err(DBError::new("Shortest path query is unavailable"));
+                        }
+                    }
                     Item::Map(MapItem::ProjectQuery(o)) => {
                         if next_available.contains(&QueryOperation::ProjectOperation) {
                             next_available = o.next_available();
@@ -246,32 +623,61 @@ impl Storage {
                             match &projection {
                                 // TODO make it more beautiful
                                 Some(r) => {
-                                    query_response_unwrapped.data =
-                                        self.fetch_found_ids(&filter_buf, &insert_buf, Some(r))?;
+                                    query_response_unwrapped.data = self.fetch_found_ids(
+                                        &filter_buf,
+                                        &insert_buf,
+                                        Some(r),
+                                        fetch_depth,
+                                    )?;
                                 }
                                 None => {
-                                    query_response_unwrapped.data =
-                                        self.fetch_found_ids(&filter_buf, &insert_buf, None)?;
+                                    query_response_unwrapped.data = self.fetch_found_ids(
+                                        &filter_buf,
+                                        &insert_buf,
+                                        None,
+                                        fetch_depth,
+                                    )?;
                                 }
                             }
                             query_response_unwrapped.status = QueryStatus::Ready;
                         }
+                        if let Some((id, _, _)) = &opened_cursor {
+                            query_response_unwrapped.meta = Meta::FindMeta(FindMeta::new_cursor(
+                                filter_buf.ids.len(),
+                                id.clone(),
+                            ));
+                        }
+                        results[step] = Some(query_response_unwrapped.clone());
                         transaction_response.add_response(query_response_unwrapped);
                     }
                 }
             }
+            if let Some((Some(id), rest, size)) = opened_cursor {
+                cursor_changes.insert(id, Some(Cursor::new(rest, size, projection, fetch_depth)));
+                self.cursors.check_limit(&cursor_changes)?;
+            }
         }
         self.sync_buf(&insert_buf)?;
-        Ok(transaction_response)
+        for (name, query) in transaction.prepared {
+            self.prepared.insert(name, query);
+        }
+        self.cursors.apply(cursor_changes);
+        let response = transaction_response.serialize();
+        if let Some(key) = transaction.idempotency_key {
+            self.idempotency.insert(key, response.clone());
+        }
+        Ok(response)
     }
 
     fn sync_buf(&mut self, buf: &InsertBuffer) -> Result<(), DBError> {
         if buf.dropped_collections.len() > 0 {
             for collection_name in &buf.dropped_collections {
-                match self.get_collection(collection_name.to_string()) {
+                match self.warehouse.remove(collection_name) {
                     Some(collection) => {
                         fs::remove_file(collection.get_path(self.wh_path.clone()))?; // TODO clean internal collection too
-                        self.warehouse.remove(collection_name);
+                        for (link, item) in &collection.values {
+                            self.references.remove(link, item);
+                        }
                     }
                     _ => {}
                 };
@@ -289,6 +695,11 @@ impl Storage {
                 };
                 let mut file = collection.get_file(self.wh_path.clone())?;
                 write!(file, "{}:{};", link.serialize(), item.serialize())?;
+                collection.bump_version(link);
+                if let Some(old_item) = collection.values.get(link) {
+                    self.references.remove(link, old_item);
+                }
+                self.references.add(link, item);
                 match item {
                     Item::Primitive(Primitive::DeletedPrimitive(_)) => {
                         collection.values.remove(link);
@@ -305,10 +716,18 @@ impl Storage {
     pub fn insert_item(
         &self,
         collection_name: String,
-        mut buf: &mut InsertBuffer,
+        buf: &mut InsertBuffer,
+        item: Item,
+    ) -> Result<Item, DBError> {
+        self.insert_item_by_link(Link::create(collection_name), buf, item)
+    }
+
+    pub fn insert_item_by_link(
+        &self,
+        link: Link,
+        buf: &mut InsertBuffer,
         item: Item,
     ) -> Result<Item, DBError> {
-        let link = Link::create(collection_name);
         match item {
             Item::Primitive(Primitive::Link(o)) => {
                 buf.insert(link.clone(), Item::Primitive(Primitive::Link(o)));
@@ -333,7 +752,7 @@ impl Storage {
                 for i in o.get_items() {
                     v.push(self.insert_item(
                         INTERNAL_COLLECTION_NAME.to_string(),
-                        &mut buf,
+                        buf,
                         i.clone(),
                     )?)?;
                 }
@@ -344,11 +763,7 @@ impl Storage {
                 for (k, v) in o.get_items() {
                     m.insert(
                         k.clone(),
-                        self.insert_item(
-                            INTERNAL_COLLECTION_NAME.to_string(),
-                            &mut buf,
-                            v.clone(),
-                        )?,
+                        self.insert_item(INTERNAL_COLLECTION_NAME.to_string(), buf, v.clone())?,
                     )?;
                 }
                 buf.insert(link.clone(), Item::Map(m));
@@ -358,16 +773,58 @@ impl Storage {
         Ok(Item::Primitive(Primitive::Link(link)))
     }
 
+    pub fn exists(&self, id: &Link, insert_buf: &InsertBuffer) -> bool {
+        match insert_buf.items.get(id) {
+            Some(Item::Primitive(Primitive::DeletedPrimitive(_))) => false,
+            Some(_) => true,
+            None => {
+                !insert_buf.dropped_collections.contains(&id.collection_name)
+                    && self
+                        .warehouse
+                        .get(id.collection_name.as_str())
+                        .is_some_and(|c| c.values.contains_key(id))
+            }
+        }
+    }
+
+    /// Version of the root object. Changes of the current transaction count as one more version.
+    pub fn get_version(&self, id: &Link, insert_buf: &InsertBuffer) -> usize {
+        let committed = if insert_buf.dropped_collections.contains(&id.collection_name) {
+            0
+        } else {
+            self.warehouse
+                .get(id.collection_name.as_str())
+                .map_or(0, |c| c.get_version(id))
+        };
+        if insert_buf.items.contains_key(id) {
+            committed + 1
+        } else {
+            committed
+        }
+    }
+
+    /// Returns the stored record without fetching its links
+    pub(crate) fn get_stored_item(&self, id: &Link, insert_buf: &InsertBuffer) -> Option<Item> {
+        match insert_buf.items.get(id) {
+            Some(item) => Some(item.clone()),
+            None => self
+                .warehouse
+                .get(id.collection_name.as_str())
+                .and_then(|c| c.values.get(id))
+                .cloned(),
+        }
+    }
+
     pub fn get_item_by_link(
         &self,
         id: &Link,
         insert_buf: &InsertBuffer,
-        counter: i32,
+        depth: usize,
         projection_rules: Option<&ProjectQuery>,
     ) -> Result<Item, DBError> {
         match insert_buf.items.get(id) {
             Some(value) => {
-                Ok(self.fetch_or_project(value, id, insert_buf, projection_rules, counter)?)
+                Ok(self.fetch_or_project(value, id, insert_buf, projection_rules, depth)?)
             }
             None => {
                 let collection = self
@@ -375,13 +832,9 @@ impl Storage {
                     .get(id.get_prefix().as_str())
                     .ok_or(DBError::new("Getting collection internal error"))?;
                 match collection.values.get(id) {
-                    Some(value) => Ok(self.fetch_or_project(
-                        value,
-                        id,
-                        insert_buf,
-                        projection_rules,
-                        counter,
-                    )?),
+                    Some(value) => {
+                        Ok(self.fetch_or_project(value, id, insert_buf, projection_rules, depth)?)
+                    }
                     None => Ok(Item::Primitive(Primitive::new(
                         NULL.to_string(),
                         "".to_string(),
@@ -397,7 +850,7 @@ impl Storage {
         link: &Link,
         insert_buf: &InsertBuffer,
         projection_rules: Option<&ProjectQuery>,
-        counter: i32,
+        depth: usize,
     ) -> Result<Item, DBError> {
         if projection_rules.is_some() {
             let result = resolve(
@@ -406,10 +859,12 @@ impl Storage {
                 link,
                 self,
                 insert_buf,
+                depth,
             )?;
             return Ok(result);
         }
-        Ok(self.fetch(value, insert_buf, counter)?)
+        // The links back to the root object are not expanded
+        self.fetch_linked(value, insert_buf, depth, &mut vec![link.clone()])
     }
 
     pub fn fetch_found_ids(
@@ -417,77 +872,262 @@ impl Storage {
         buf: &FilterBuffer,
         insert_buf: &InsertBuffer,
         projection_rules: Option<&ProjectQuery>,
+        depth: usize,
     ) -> Result<Item, DBError> {
         let mut res = ResponseObjects::new("".to_string())?;
         for id in buf.ids.clone() {
             res.insert(
                 Primitive::from(id.clone()),
-                self.get_item_by_link(&id, insert_buf, 0, projection_rules)?,
+                self.get_item_by_link(&id, insert_buf, depth, projection_rules)?,
             )?;
         }
         Ok(res.to_item())
     }
 
-    pub fn fetch(
+    /// Expands the links up to the server default depth
+    pub fn fetch(&self, item: &Item, insert_buf: &InsertBuffer) -> Result<Item, DBError> {
+        self.fetch_with_depth(item, insert_buf, self.fetch_depth)
+    }
+
+    pub(crate) fn fetch_with_depth(
         &self,
         item: &Item,
         insert_buf: &InsertBuffer,
-        counter: i32,
+        depth: usize,
+    ) -> Result<Item, DBError> {
+        self.fetch_linked(item, insert_buf, depth, &mut vec![])
+    }
+
+    /// Expands a part of the root object, the links back to the root are not expanded
+    pub(crate) fn fetch_part(
+        &self,
+        item: &Item,
+        root: &Link,
+        insert_buf: &InsertBuffer,
+        depth: usize,
+    ) -> Result<Item, DBError> {
+        self.fetch_linked(item, insert_buf, depth, &mut vec![root.clone()])
+    }
+
+    /// Expands the links up to the depth. The parts of the objects stored in `_internal` are always expanded.
+    /// The links to the objects, which are already expanded on the path, are returned as back-references.
+    fn fetch_linked(
+        &self,
+        item: &Item,
+        insert_buf: &InsertBuffer,
+        depth: usize,
+        path: &mut Vec<Link>,
     ) -> Result<Item, DBError> {
-        let counter = counter + 1;
-        if counter > FETCH_DEPTH_LIMIT {
-            return Err(DBError::new("Fetch recursion error"));
-        }
         match item {
             Item::Primitive(Primitive::Link(o)) => {
-                let i = self.get_item_by_link(o, insert_buf, counter, None)?;
-                Ok(self.fetch(&i, insert_buf, counter)?)
+                let internal = o.collection_name == INTERNAL_COLLECTION_NAME;
+                if internal {
+                    // Internal links are always expanded, so their cycles are errors
+                    if path.contains(o) || path.len() >= FETCH_DEPTH_LIMIT as usize {
+                        return Err(DBError::new("Fetch recursion error"));
+                    }
+                } else if depth == 0 || path.contains(o) {
+                    return Ok(item.clone());
+                }
+                let value = match self.get_stored_item(o, insert_buf) {
+                    Some(value) => value,
+                    None => {
+                        return Ok(Item::Primitive(Primitive::new(
+                            NULL.to_string(),
+                            "".to_string(),
+                        )?))
+                    }
+                };
+                let depth = if internal { depth } else { depth - 1 };
+                path.push(o.clone());
+                let res = self.fetch_linked(&value, insert_buf, depth, path);
+                path.pop();
+                res
             }
-            Item::Primitive(Primitive::StringPrimitive(_)) => Ok(item.clone()),
-            Item::Primitive(Primitive::NumberPrimitive(_)) => Ok(item.clone()),
-            Item::Primitive(Primitive::UTSPrimitive(_)) => Ok(item.clone()),
-            Item::Primitive(Primitive::BoolPrimitive(_)) => Ok(item.clone()),
-            Item::Primitive(Primitive::NullPrimitive(_)) => Ok(item.clone()),
-            Item::Primitive(Primitive::DeletedPrimitive(_)) => Ok(item.clone()),
             Item::Vector(o) => {
                 let mut new_vec: VectorItem = VectorItem::new(STORAGE_VECTOR.to_string())?;
                 for i in o.get_items() {
-                    new_vec.push(self.fetch(i, insert_buf, counter)?)?;
+                    new_vec.push(self.fetch_linked(i, insert_buf, depth, path)?)?;
                 }
                 Ok(Item::Vector(new_vec))
             }
             Item::Map(o) => {
-                // if projection_rules.is_some() {
-                //     let result = projection_rules.unwrap().resolve()?;
-                //     return Ok(result);
-                // }
-
                 let mut new_map: MapItem = MapItem::new(STORAGE_MAP.to_string())?;
                 for (k, v) in o.get_items() {
-                    new_map.insert(k.clone(), self.fetch(&v, insert_buf, counter)?)?;
+                    new_map.insert(k.clone(), self.fetch_linked(&v, insert_buf, depth, path)?)?;
                 }
-
                 Ok(Item::Map(new_map))
             }
-            _ => Err(DBError::new("Internal fetch error")),
+            _ => Ok(item.clone()),
         }
     }
 
     pub fn get_value_by_link(&self, id: &Link) -> Result<Item, DBError> {
         match self.get_collection(id.get_prefix()) {
-            Some(collection) => Ok(collection.get_value(&id)?),
+            Some(collection) => Ok(collection.get_value(id)?),
             None => Err(DBError::new("Getting value by link error")),
         }
     }
 
-    fn fetch_value_by_link(&self, id: &Link) -> Result<(Link, Item), DBError> {
-        let value = self.get_value_by_link(id)?;
+    pub fn resolve_link(
+        &self,
+        id: &Link,
+        insert_buf: &InsertBuffer,
+    ) -> Result<(Link, Item), DBError> {
+        let mut link = id.clone();
+        for _ in 0..FETCH_DEPTH_LIMIT {
+            let value = match insert_buf.items.get(&link) {
+                Some(v) => v.clone(),
+                None => match self
+                    .get_collection(link.get_prefix())
+                    .and_then(|c| c.values.get(&link))
+                {
+                    Some(v) => v.clone(),
+                    None => Item::Primitive(Primitive::new(NULL.to_string(), "".to_string())?),
+                },
+            };
+            match value {
+                Item::Primitive(Primitive::Link(next)) => link = next,
+                _ => return Ok((link, value)),
+            }
+        }
+        Err(DBError::new("Fetch recursion error"))
+    }
+
+    fn get_referencing_items(&self, id: &Link, insert_buf: &InsertBuffer) -> Vec<Link> {
+        let mut res: Vec<Link> = vec![];
+        for link in self.references.get(id) {
+            if !insert_buf.items.contains_key(link)
+                && !insert_buf
+                    .dropped_collections
+                    .contains(&link.collection_name)
+            {
+                res.push(link.clone());
+            }
+        }
+        for (link, item) in &insert_buf.items {
+            if links_to(item).contains(&id) {
+                res.push(link.clone());
+            }
+        }
+        res
+    }
+
+    fn get_reference_keys(
+        &self,
+        container: &Link,
+        id: &Link,
+        insert_buf: &InsertBuffer,
+    ) -> Result<Vec<String>, DBError> {
+        let value = match insert_buf.items.get(container) {
+            Some(v) => v.clone(),
+            None => self.get_value_by_link(container)?,
+        };
+        let target = Item::Primitive(Primitive::Link(id.clone()));
+        let mut keys: Vec<String> = vec![];
         match value {
-            Item::Primitive(Primitive::Link(link)) => {
-                return self.fetch_value_by_link(&link);
+            Item::Map(MapItem::StorageMap(o)) => {
+                for (k, v) in &o.values {
+                    if *v == target {
+                        keys.push(k.get_value().to_string());
+                    }
+                }
+            }
+            Item::Vector(VectorItem::StorageVector(o)) => {
+                for (i, v) in o.items.iter().enumerate() {
+                    if *v == target {
+                        keys.push(i.to_string());
+                    }
+                }
             }
-            _ => Ok((id.clone(), value)),
+            _ => keys.push("".to_string()),
         }
+        Ok(keys)
+    }
+
+    /// Returns documents which contain a link to the given id together with the paths
+    /// of the links. Links stored as documents themselves are reported with the `root` path.
+    pub fn get_references(
+        &self,
+        id: &Link,
+        insert_buf: &InsertBuffer,
+    ) -> Result<Vec<(Link, String)>, DBError> {
+        let mut res: Vec<(Link, String)> = vec![];
+        let mut stack: Vec<(Link, String)> = vec![(id.clone(), "".to_string())];
+        let mut visited: HashSet<(Link, Link)> = HashSet::new();
+        while let Some((link, path)) = stack.pop() {
+            for container in self.get_referencing_items(&link, insert_buf) {
+                if !visited.insert((container.clone(), link.clone())) {
+                    continue;
+                }
+                for key in self.get_reference_keys(&container, &link, insert_buf)? {
+                    let full_path = match (key.is_empty(), path.is_empty()) {
+                        (true, _) => path.clone(),
+                        (false, true) => key,
+                        (false, false) => format!("{}{}{}", key, PATH_SEPARATOR, path),
+                    };
+                    if container.collection_name == INTERNAL_COLLECTION_NAME {
+                        stack.push((container.clone(), full_path));
+                    } else if full_path.is_empty() {
+                        res.push((container.clone(), ROOT.to_string()));
+                    } else {
+                        res.push((container.clone(), full_path));
+                    }
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn get_values_by_path(
+        &self,
+        path: &PathToValue,
+        id: &Link,
+        insert_buf: &InsertBuffer,
+    ) -> Result<Vec<Item>, DBError> {
+        Ok(self
+            .get_linked_values_by_path(path, id, insert_buf)?
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect())
+    }
+
+    pub fn get_linked_values_by_path(
+        &self,
+        path: &PathToValue,
+        id: &Link,
+        insert_buf: &InsertBuffer,
+    ) -> Result<Vec<(Link, Item)>, DBError> {
+        let mut items: Vec<(Link, Item)> = vec![self.resolve_link(id, insert_buf)?];
+        for sub_path in path.value.split(PATH_SEPARATOR) {
+            let mut found: Vec<&Item> = vec![];
+            for (_, item) in &items {
+                match item {
+                    Item::Map(MapItem::StorageMap(o)) => {
+                        if sub_path == WILDCARD_PATH {
+                            found.extend(o.values.values());
+                        } else if let Some(v) = o.get_by_str(sub_path)? {
+                            found.push(v);
+                        }
+                    }
+                    Item::Vector(VectorItem::StorageVector(o)) => {
+                        if sub_path == WILDCARD_PATH {
+                            found.extend(o.items.iter());
+                        } else if let Some(v) = o.get_by_str(sub_path)? {
+                            found.push(v);
+                        }
+                    }
+                    // primitives have no sub values, wildcards match only the existing ones
+                    _ => {}
+                }
+            }
+            let mut next_items: Vec<(Link, Item)> = vec![];
+            for link in found {
+                next_items.push(self.resolve_link(&link.to_link()?, insert_buf)?);
+            }
+            items = next_items;
+        }
+        Ok(items)
     }
 
     pub fn get_value_by_path(
@@ -516,7 +1156,7 @@ impl Storage {
                 Item::Map(MapItem::StorageMap(o)) => match o.get_by_str(sub_path)? {
                     Some(found_link) => {
                         let (fetched_link, fetched_value) =
-                            self.fetch_value_by_link(&found_link.to_link()?)?;
+                            self.resolve_link(&found_link.to_link()?, insert_buf)?;
                         sub_item = Some(FoundSubItem {
                             container_id: last_link,
                             container_value: item.clone(),
@@ -538,14 +1178,26 @@ impl Storage {
                 },
                 Item::Vector(VectorItem::StorageVector(o)) => match o.get_by_str(sub_path)? {
                     Some(found_link) => {
+                        let (fetched_link, fetched_value) =
+                            self.resolve_link(&found_link.to_link()?, insert_buf)?;
                         sub_item = Some(FoundSubItem {
                             container_id: last_link,
                             container_value: item.clone(),
                             key: sub_path.to_string(),
-                            value: Some(self.get_value_by_link(&found_link.to_link()?)?),
+                            value: Some(fetched_value.clone()),
                         });
-                        last_link = found_link.to_link()?;
-                        item = self.get_value_by_link(&last_link.clone())?;
+                        last_link = fetched_link;
+                        item = fetched_value;
+                    }
+                    // $last of an empty vector is reported with the vector,
+                    // so updates can tell it from a missing path
+                    None if sub_path == LAST_INDEX_PATH && o.items.is_empty() => {
+                        return Ok(Some(FoundSubItem {
+                            container_id: last_link,
+                            container_value: item.clone(),
+                            key: sub_path.to_string(),
+                            value: None,
+                        }));
                     }
                     None => {
                         return Ok(None);
@@ -1,5 +1,10 @@
 pub mod buffer;
 pub(crate) mod collection;
 pub mod common;
+pub(crate) mod cursors;
+pub(crate) mod idempotency;
 pub mod main;
+pub(crate) mod prepared;
+pub(crate) mod procedures;
+pub(crate) mod references;
 pub mod transaction;
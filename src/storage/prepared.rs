@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::constants::PREPARED_QUERIES_LIMIT;
+use crate::storage::transaction::TransactionItem;
+use crate::{DBError, Primitive};
+
+/// Parsed steps of a prepared query and the prefixes of its parameters
+#[derive(Debug, Clone)]
+pub struct PreparedQuery {
+    steps: Vec<TransactionItem>,
+    params: HashMap<String, String>,
+}
+
+impl PreparedQuery {
+    pub(crate) fn new(steps: Vec<TransactionItem>) -> Result<Self, DBError> {
+        let mut params: HashMap<String, String> = HashMap::new();
+        for item in &steps {
+            if let TransactionItem::Step(step) = item {
+                for param in step.get_params() {
+                    let kind = params
+                        .entry(param.get_name().to_string())
+                        .or_insert_with(|| param.get_kind().to_string());
+                    if kind != param.get_kind() {
+                        return Err(DBError::new(
+                            format!("Parameter {} has different types", param.get_name()).as_str(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(Self { steps, params })
+    }
+
+    pub(crate) fn bind(
+        &self,
+        values: &HashMap<String, Primitive>,
+    ) -> Result<Vec<TransactionItem>, DBError> {
+        for (name, kind) in &self.params {
+            match values.get(name) {
+                Some(value) if value.get_prefix() == *kind => {}
+                Some(_) => {
+                    return Err(DBError::new(
+                        format!("Parameter {} must have the {} prefix", name, kind).as_str(),
+                    ))
+                }
+                None => {
+                    return Err(DBError::new(
+                        format!("Parameter {} is missing", name).as_str(),
+                    ))
+                }
+            }
+        }
+        if let Some(name) = values.keys().find(|k| !self.params.contains_key(*k)) {
+            return Err(DBError::new(
+                format!("Unexpected parameter {}", name).as_str(),
+            ));
+        }
+        let mut steps = self.steps.clone();
+        for item in steps.iter_mut() {
+            if let TransactionItem::Step(step) = item {
+                step.bind_params(values)?;
+            }
+        }
+        Ok(steps)
+    }
+}
+
+/// Prepared queries by their names.
+/// They live in memory, so the clients register them again after a restart.
+#[derive(Debug)]
+pub struct PreparedQueries {
+    queries: HashMap<String, PreparedQuery>,
+}
+
+impl PreparedQueries {
+    pub(crate) fn new() -> Self {
+        Self {
+            queries: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn check_limit(&self, prepared: &[(String, PreparedQuery)]) -> Result<(), DBError> {
+        let new = prepared
+            .iter()
+            .filter(|(name, _)| !self.queries.contains_key(name))
+            .count();
+        if self.queries.len() + new > PREPARED_QUERIES_LIMIT {
+            return Err(DBError::new("Too many prepared queries"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn insert(&mut self, name: String, query: PreparedQuery) {
+        self.queries.insert(name, query);
+    }
+
+    /// Replaces the executions with the steps of the prepared queries.
+    /// Queries prepared by the same transaction are available too.
+    pub(crate) fn expand(
+        &self,
+        items: Vec<(usize, TransactionItem)>,
+        prepared: &[(String, PreparedQuery)],
+    ) -> Result<Vec<(usize, TransactionItem)>, DBError> {
+        let mut res: Vec<(usize, TransactionItem)> = vec![];
+        for (step, item) in items {
+            match item {
+                TransactionItem::Execute(name, values) => match prepared
+                    .iter()
+                    .rev()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, query)| query)
+                    .or_else(|| self.queries.get(&name))
+                {
+                    Some(query) => res.extend(query.bind(&values)?.into_iter().map(|o| (step, o))),
+                    None => {
+                        return Err(DBError::new(
+                            format!("Prepared query {} does not exist", name).as_str(),
+                        ))
+                    }
+                },
+                TransactionItem::Step(step) if !step.get_params().is_empty() => {
+                    return Err(DBError::new(
+                        "Parameters can be used only in prepared queries",
+                    ))
+                }
+                _ => res.push((step, item)),
+            }
+        }
+        Ok(res)
+    }
+}
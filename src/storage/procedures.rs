@@ -0,0 +1,185 @@
+use crate::constants::{PROCEDURES_COLLECTION_NAME, STORAGE_MAP};
+use crate::data_types::map::template::QueryTemplate;
+use crate::data_types::primitives::number::NumberPrimitive;
+use crate::storage::buffer::InsertBuffer;
+use crate::{DBError, Item, Link, MapItem, Primitive, Storage, StringPrimitive, TySONMap};
+
+const NAME_FIELD: &str = "name";
+const VERSION_FIELD: &str = "version";
+const TEMPLATE_FIELD: &str = "template";
+
+/// Parses `name` or `name:version`
+fn parse_versioned_name(value: &str) -> Result<(String, Option<usize>), DBError> {
+    let error = || DBError::new(format!("Invalid procedure name {}", value).as_str());
+    let (name, version) = match value.split_once(':') {
+        Some((name, version)) => (name, Some(version.parse::<usize>().map_err(|_| error())?)),
+        None => (value, None),
+    };
+    if name.is_empty() || version == Some(0) {
+        return Err(error());
+    }
+    Ok((name.to_string(), version))
+}
+
+/// Stored procedure definition.
+/// `procedure|name:3|` fails, if the new definition would not become the version 3.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Procedure {
+    pub(crate) name: String,
+    pub(crate) version: Option<usize>,
+}
+
+impl Procedure {
+    pub(crate) fn new(value: &str) -> Result<Self, DBError> {
+        let (name, version) = parse_versioned_name(value)?;
+        Ok(Self { name, version })
+    }
+}
+
+/// Stored procedure call.
+/// `call|name|` runs the latest version of the procedure and `call|name:2|` the second one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Call {
+    pub(crate) name: String,
+    pub(crate) version: Option<usize>,
+}
+
+impl Call {
+    pub(crate) fn new(value: &str) -> Result<Self, DBError> {
+        let (name, version) = parse_versioned_name(value)?;
+        Ok(Self { name, version })
+    }
+}
+
+#[derive(Debug)]
+struct ProcedureRecord {
+    name: String,
+    version: usize,
+    template: QueryTemplate,
+}
+
+fn to_record(item: &Item) -> Result<Option<ProcedureRecord>, DBError> {
+    let error = || DBError::new("Internal error: broken procedure");
+    match item {
+        Item::Map(MapItem::StorageMap(o)) => {
+            let name = match o.get_by_str(NAME_FIELD)? {
+                Some(Item::Primitive(Primitive::StringPrimitive(v))) => v.get_value().to_string(),
+                _ => return Err(error()),
+            };
+            let version = match o.get_by_str(VERSION_FIELD)? {
+                Some(Item::Primitive(Primitive::NumberPrimitive(v))) => v.get_value() as usize,
+                _ => return Err(error()),
+            };
+            let template = match o.get_by_str(TEMPLATE_FIELD)? {
+                Some(Item::Map(MapItem::QueryTemplate(v))) => v.clone(),
+                _ => return Err(error()),
+            };
+            Ok(Some(ProcedureRecord {
+                name,
+                version,
+                template,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Versions of the procedure, including the ones defined by the running transaction
+fn get_records(
+    storage: &Storage,
+    name: &str,
+    insert_buf: &InsertBuffer,
+) -> Result<Vec<ProcedureRecord>, DBError> {
+    let mut res: Vec<ProcedureRecord> = vec![];
+    if let Some(collection) = storage.get_collection(PROCEDURES_COLLECTION_NAME.to_string()) {
+        for (id, item) in &collection.values {
+            if !insert_buf.items.contains_key(id) {
+                if let Some(record) = to_record(item)? {
+                    res.push(record);
+                }
+            }
+        }
+    }
+    for (id, item) in &insert_buf.items {
+        if id.collection_name == PROCEDURES_COLLECTION_NAME {
+            if let Some(record) = to_record(item)? {
+                res.push(record);
+            }
+        }
+    }
+    res.retain(|r| r.name == name);
+    Ok(res)
+}
+
+/// Stores the template as the next version of the procedure.
+/// The previous versions stay available for the pinned calls.
+pub(crate) fn define(
+    storage: &Storage,
+    procedure: &Procedure,
+    template: QueryTemplate,
+    insert_buf: &mut InsertBuffer,
+) -> Result<(), DBError> {
+    let latest = get_records(storage, procedure.name.as_str(), insert_buf)?
+        .iter()
+        .map(|r| r.version)
+        .max()
+        .unwrap_or(0);
+    if let Some(version) = procedure.version {
+        if version != latest + 1 {
+            return Err(DBError::new(
+                format!("Procedure {} has version {}", procedure.name, latest).as_str(),
+            ));
+        }
+    }
+    let mut record = MapItem::new(STORAGE_MAP.to_string())?;
+    record.insert(
+        Primitive::from(StringPrimitive::from(NAME_FIELD)),
+        Item::from(Primitive::from(StringPrimitive::from(
+            procedure.name.as_str(),
+        ))),
+    )?;
+    record.insert(
+        Primitive::from(StringPrimitive::from(VERSION_FIELD)),
+        Item::from(Primitive::NumberPrimitive(NumberPrimitive::from(
+            latest + 1,
+        ))),
+    )?;
+    record.insert(
+        Primitive::from(StringPrimitive::from(TEMPLATE_FIELD)),
+        Item::Map(MapItem::QueryTemplate(template)),
+    )?;
+    insert_buf.insert(
+        Link::create(PROCEDURES_COLLECTION_NAME.to_string()),
+        Item::Map(record),
+    );
+    Ok(())
+}
+
+/// Finds the template of the called procedure version, the latest one by default
+pub(crate) fn load(
+    storage: &Storage,
+    call: &Call,
+    insert_buf: &InsertBuffer,
+) -> Result<QueryTemplate, DBError> {
+    let records = get_records(storage, call.name.as_str(), insert_buf)?;
+    if records.is_empty() {
+        return Err(DBError::new(
+            format!("Procedure {} does not exist", call.name).as_str(),
+        ));
+    }
+    let record = match call.version {
+        Some(version) => records.into_iter().find(|r| r.version == version),
+        None => records.into_iter().max_by_key(|r| r.version),
+    };
+    match record {
+        Some(o) => Ok(o.template),
+        None => Err(DBError::new(
+            format!(
+                "Procedure {} version {} does not exist",
+                call.name,
+                call.version.unwrap_or(0)
+            )
+            .as_str(),
+        )),
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Item, Link, MapItem, Primitive, VectorItem};
+
+pub(crate) fn links_to(item: &Item) -> Vec<&Link> {
+    let mut res: Vec<&Link> = vec![];
+    match item {
+        Item::Primitive(Primitive::Link(o)) => res.push(o),
+        Item::Map(MapItem::StorageMap(o)) => {
+            for v in o.values.values() {
+                if let Item::Primitive(Primitive::Link(l)) = v {
+                    res.push(l);
+                }
+            }
+        }
+        Item::Vector(VectorItem::StorageVector(o)) => {
+            for v in &o.items {
+                if let Item::Primitive(Primitive::Link(l)) = v {
+                    res.push(l);
+                }
+            }
+        }
+        _ => {}
+    }
+    res
+}
+
+#[derive(Debug)]
+pub struct ReferenceIndex {
+    referenced_by: HashMap<Link, HashSet<Link>>,
+}
+
+impl ReferenceIndex {
+    pub(crate) fn new() -> Self {
+        Self {
+            referenced_by: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, link: &Link, item: &Item) {
+        for target in links_to(item) {
+            self.referenced_by
+                .entry(target.clone())
+                .or_default()
+                .insert(link.clone());
+        }
+    }
+
+    pub(crate) fn remove(&mut self, link: &Link, item: &Item) {
+        for target in links_to(item) {
+            if let Some(sources) = self.referenced_by.get_mut(target) {
+                sources.remove(link);
+                if sources.is_empty() {
+                    self.referenced_by.remove(target);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn get(&self, target: &Link) -> Vec<&Link> {
+        match self.referenced_by.get(target) {
+            Some(sources) => sources.iter().collect(),
+            None => vec![],
+        }
+    }
+}
@@ -1,7 +1,23 @@
+use std::collections::HashMap;
+
+use crate::constants::{
+    CALL, CLOSE, EXECUTE, IDEMPOTENCY_KEY, MORE, PREPARE, PROCEDURE, ROLLBACK_TO, SAVEPOINT,
+};
+use crate::data_types::map::template::QueryTemplate;
+use crate::data_types::modifier::ModifierItem;
+use crate::data_types::primitives::param::Param;
 use crate::query::queryset::QuerySet;
-use crate::{DBError, Desereilize, Item, Primitive, TySONPrimitive, VectorItem};
+use crate::response::QueryResponse;
+use crate::storage::prepared::PreparedQuery;
+use crate::storage::procedures::{Call, Procedure};
+use crate::tyson::item::BaseTySONItemInterface;
+use crate::tyson::modifier::TySONModifier;
+use crate::{
+    DBError, Desereilize, Item, MapItem, Primitive, TySONMap, TySONPrimitive, TySONVector,
+    VectorItem,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TransactionStep {
     pub(crate) collection_name: String,
     pub(crate) query_set: QuerySet,
@@ -18,6 +34,10 @@ impl TransactionStep {
                 collection_name,
                 query_set: QuerySet::from(q),
             }),
+            Item::Map(MapItem::InsertWithIdsQuery(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
             Item::Vector(VectorItem::FindQuery(q)) => Ok(Self {
                 collection_name,
                 query_set: QuerySet::from(q),
@@ -30,18 +50,223 @@ impl TransactionStep {
                 collection_name,
                 query_set: QuerySet::from(q),
             }),
+            Item::Map(MapItem::OnDeleteQuery(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
             Item::Primitive(Primitive::DeleteQuery(q)) => Ok(Self {
                 collection_name,
                 query_set: QuerySet::from(q),
             }),
+            Item::Primitive(Primitive::CountAccumulator(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
+            Item::Modifier(ModifierItem::DistinctQuery(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
+            Item::Modifier(ModifierItem::AssertQuery(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
+            Item::Primitive(Primitive::ReferencedByQuery(q)) => Ok(Self {
+                collection_name,
+                query_set: QuerySet::from(q),
+            }),
             _ => Err(DBError::new("Unexpected queryset item type")),
         }
     }
+
+    /// Replaces the references to the results of the previous steps with their values
+    pub(crate) fn resolve_step_results(
+        &mut self,
+        results: &[Option<QueryResponse>],
+    ) -> Result<(), DBError> {
+        self.replace_primitives(&|p| match p {
+            Primitive::StepResult(o) => Some(o.resolve(results)),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn get_params(&self) -> Vec<Param> {
+        let mut params: Vec<Param> = vec![];
+        for item in &self.query_set.items {
+            visit_primitives(item, &mut |p| {
+                if let Primitive::Param(o) = p {
+                    params.push(o.clone());
+                }
+            });
+        }
+        params
+    }
+
+    /// Replaces the placeholders of a prepared query with the checked parameters
+    pub(crate) fn bind_params(
+        &mut self,
+        params: &HashMap<String, Primitive>,
+    ) -> Result<(), DBError> {
+        self.replace_primitives(&|p| match p {
+            Primitive::Param(o) => Some(match params.get(o.get_name()) {
+                Some(value) => Ok(Item::from(value.clone())),
+                None => Err(DBError::new(
+                    format!("Parameter {} is missing", o.get_name()).as_str(),
+                )),
+            }),
+            _ => None,
+        })
+    }
+
+    /// `f` returns the new value for the primitives, which must be replaced
+    fn replace_primitives(
+        &mut self,
+        f: &impl Fn(&Primitive) -> Option<Result<Item, DBError>>,
+    ) -> Result<(), DBError> {
+        for item in self.query_set.items.iter_mut() {
+            // Only the items with placeholders are rebuilt
+            let mut found = false;
+            visit_primitives(item, &mut |p| {
+                found |= matches!(p, Primitive::StepResult(_) | Primitive::Param(_))
+            });
+            if found {
+                *item = replace_primitives(item, f)?;
+            }
+        }
+        Ok(())
+    }
 }
 
-#[derive(Debug)]
+fn visit_primitives(item: &Item, f: &mut impl FnMut(&Primitive)) {
+    match item {
+        Item::Primitive(o) => f(o),
+        Item::Map(o) => {
+            for (k, v) in o.get_items() {
+                f(&k);
+                visit_primitives(&v, f);
+            }
+        }
+        Item::Vector(o) => {
+            for i in o.get_items() {
+                visit_primitives(i, f);
+            }
+        }
+        Item::Modifier(o) => visit_primitives(o.get_value(), f),
+    }
+}
+
+fn replace_primitives(
+    item: &Item,
+    f: &impl Fn(&Primitive) -> Option<Result<Item, DBError>>,
+) -> Result<Item, DBError> {
+    match item {
+        Item::Primitive(o) => f(o).unwrap_or_else(|| Ok(item.clone())),
+        Item::Map(o) => {
+            let mut map = MapItem::new(o.get_prefix())?;
+            for (k, v) in o.get_items() {
+                // Keys can be placeholders too, e.g. the paths of the find operators
+                let key = match f(&k) {
+                    Some(res) => match res? {
+                        Item::Primitive(p) => p,
+                        _ => return Err(DBError::new("Map keys can be only primitives")),
+                    },
+                    None => k,
+                };
+                map.insert(key, replace_primitives(&v, f)?)?;
+            }
+            Ok(Item::Map(map))
+        }
+        Item::Vector(o) => {
+            let mut vector = VectorItem::new(o.get_prefix())?;
+            for i in o.get_items() {
+                vector.push(replace_primitives(i, f)?)?;
+            }
+            Ok(Item::Vector(vector))
+        }
+        Item::Modifier(o) => Ok(Item::Modifier(ModifierItem::new(
+            o.get_prefix(),
+            replace_primitives(o.get_value(), f)?,
+        )?)),
+    }
+}
+
+/// Savepoints, rollbacks, prepared query executions and cursor commands are kept in the order of the steps
+#[derive(Debug, Clone)]
+pub enum TransactionItem {
+    Step(TransactionStep),
+    Savepoint(String),
+    RollbackTo(String),
+    Execute(String, HashMap<String, Primitive>),
+    Procedure(Procedure, QueryTemplate),
+    Call(Call, HashMap<String, Primitive>),
+    More(String),
+    Close(String),
+}
+
+fn get_params(item: Item) -> Result<HashMap<String, Primitive>, DBError> {
+    match item {
+        Item::Map(MapItem::StorageMap(params)) => {
+            let mut values: HashMap<String, Primitive> = HashMap::new();
+            for (k, v) in params.values {
+                match v {
+                    Item::Primitive(value) => {
+                        values.insert(k.get_value().to_string(), value);
+                    }
+                    _ => return Err(DBError::new("Parameters must be primitives")),
+                }
+            }
+            Ok(values)
+        }
+        _ => Err(DBError::new("Parameters must be a map")),
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Transaction {
-    pub(crate) steps: Vec<TransactionStep>,
+    /// Items with their indexes, the transaction-level options take indexes too
+    pub(crate) steps: Vec<(usize, TransactionItem)>,
+    items: usize,
+    pub(crate) idempotency_key: Option<String>,
+    pub(crate) prepared: Vec<(String, PreparedQuery)>,
+}
+
+impl Transaction {
+    /// Parses the steps of a prepared query or a procedure.
+    /// A template can roll back only to its own savepoints, the caller's work is never discarded.
+    pub(crate) fn from_template(template: QueryTemplate) -> Result<Vec<TransactionItem>, DBError> {
+        let transaction = Transaction::deserialize("".to_string(), template.source)?;
+        if transaction.idempotency_key.is_some()
+            || !transaction.prepared.is_empty()
+            || transaction.steps.iter().any(|(_, i)| {
+                !matches!(
+                    i,
+                    TransactionItem::Step(_)
+                        | TransactionItem::Savepoint(_)
+                        | TransactionItem::RollbackTo(_)
+                )
+            })
+        {
+            return Err(DBError::new(
+                "Templates can contain only steps, savepoints and rollbacks",
+            ));
+        }
+        let mut savepoints: Vec<&String> = vec![];
+        for (_, item) in &transaction.steps {
+            match item {
+                TransactionItem::Savepoint(name) => savepoints.push(name),
+                TransactionItem::RollbackTo(name) if !savepoints.contains(&name) => {
+                    return Err(DBError::new(
+                        format!("Savepoint {} is not created by the template", name).as_str(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(transaction.steps.into_iter().map(|(_, i)| i).collect())
+    }
+
+    fn add(&mut self, item: TransactionItem) {
+        self.steps.push((self.items, item));
+    }
 }
 
 impl Desereilize for Transaction {
@@ -50,14 +275,19 @@ impl Desereilize for Transaction {
     }
 
     fn new(_: String) -> Self {
-        Self { steps: vec![] }
+        Self {
+            steps: vec![],
+            items: 0,
+            idempotency_key: None,
+            prepared: vec![],
+        }
     }
 
     fn push(&mut self, data: (Primitive, Item)) -> Result<bool, DBError> {
         match data.0 {
             Primitive::CollectionName(o) => {
                 let step: TransactionStep = TransactionStep::new(o.get_string_value(), data.1)?;
-                self.steps.push(step);
+                self.add(TransactionItem::Step(step));
             }
             _ => {
                 return Err(DBError::new(
@@ -67,4 +297,77 @@ impl Desereilize for Transaction {
         }
         Ok(true)
     }
+
+    /// The transaction-level commands are parsed by their prefixes,
+    /// so they never shadow the links to the collections with the same names
+    fn push_pair(&mut self, prefix: String, value: String, data: Item) -> Result<bool, DBError> {
+        match prefix.as_str() {
+            SAVEPOINT => match data {
+                Item::Primitive(Primitive::StringPrimitive(name)) => {
+                    self.add(TransactionItem::Savepoint(name.get_value().to_string()));
+                }
+                _ => return Err(DBError::new("Savepoint name must be a string")),
+            },
+            ROLLBACK_TO => match data {
+                Item::Primitive(Primitive::StringPrimitive(name)) => {
+                    self.add(TransactionItem::RollbackTo(name.get_value().to_string()));
+                }
+                _ => return Err(DBError::new("Savepoint name must be a string")),
+            },
+            PREPARE => match data {
+                Item::Map(MapItem::QueryTemplate(template)) => {
+                    let query = PreparedQuery::new(Transaction::from_template(template)?)?;
+                    self.prepared.push((value, query));
+                }
+                _ => return Err(DBError::new("Prepared query must be a template")),
+            },
+            EXECUTE => {
+                let values = get_params(data)?;
+                self.add(TransactionItem::Execute(value, values));
+            }
+            PROCEDURE => match data {
+                Item::Map(MapItem::QueryTemplate(template)) => {
+                    let procedure = Procedure::new(value.as_str())?;
+                    // Checks the template before it is stored
+                    PreparedQuery::new(Transaction::from_template(template.clone())?)?;
+                    self.add(TransactionItem::Procedure(procedure, template));
+                }
+                _ => return Err(DBError::new("Procedure must be a template")),
+            },
+            CALL => {
+                let call = Call::new(value.as_str())?;
+                let values = get_params(data)?;
+                self.add(TransactionItem::Call(call, values));
+            }
+            MORE => match data {
+                Item::Primitive(Primitive::StringPrimitive(id)) => {
+                    self.add(TransactionItem::More(id.get_value().to_string()));
+                }
+                _ => return Err(DBError::new("Cursor id must be a string")),
+            },
+            CLOSE => match data {
+                Item::Primitive(Primitive::StringPrimitive(id)) => {
+                    self.add(TransactionItem::Close(id.get_value().to_string()));
+                }
+                _ => return Err(DBError::new("Cursor id must be a string")),
+            },
+            IDEMPOTENCY_KEY => match data {
+                Item::Primitive(Primitive::StringPrimitive(key)) => {
+                    if self.idempotency_key.is_some() {
+                        return Err(DBError::new(
+                            "Transaction can contain only one idempotency key",
+                        ));
+                    }
+                    self.idempotency_key = Some(key.get_value().to_string());
+                }
+                _ => return Err(DBError::new("Idempotency key must be a string")),
+            },
+            _ => {
+                let key = self.new_primitive(prefix, value)?;
+                self.push((key, data))?;
+            }
+        }
+        self.items += 1;
+        Ok(true)
+    }
 }
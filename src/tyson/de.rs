@@ -18,7 +18,7 @@ struct TySONParser;
 pub trait Desereilize {
     fn get_name(&self) -> String;
 
-    fn deserialize_primitive(&self, pair: Pair<Rule>) -> Result<Primitive, DBError> {
+    fn get_prefix_and_value(&self, pair: Pair<Rule>) -> (String, String) {
         let mut data: String = String::new();
         let mut prefix: String = String::new();
         for pair in pair.into_inner() {
@@ -31,6 +31,11 @@ pub trait Desereilize {
                 }
             }
         }
+        (prefix, data)
+    }
+
+    fn deserialize_primitive(&self, pair: Pair<Rule>) -> Result<Primitive, DBError> {
+        let (prefix, data) = self.get_prefix_and_value(pair);
         Ok(Self::new_primitive(&self, prefix, data)?)
     }
 
@@ -70,6 +75,14 @@ pub trait Desereilize {
             .as_str()
             .to_string();
         let mut map = Self::new_map(prefix)?;
+        if let MapItem::QueryTemplate(template) = &mut map {
+            // Template keys are transaction items, the transaction parses them
+            template.source = inner_rules
+                .map(|pair| pair.as_str())
+                .collect::<Vec<&str>>()
+                .join(",");
+            return Ok(map);
+        }
         for pair in inner_rules {
             let mut inner_rules = pair.into_inner();
             let left = Self::deserialize_primitive(
@@ -125,13 +138,11 @@ pub trait Desereilize {
                     let mut inner_rules = pair.into_inner();
                     match inner_rules.next() {
                         Some(v) => {
-                            let key = result.deserialize_primitive(v)?;
-                            result.push((
-                                key,
-                                result.route_deserialization(
-                                    inner_rules.next().ok_or(DBError::unexpected_parsing())?,
-                                )?,
-                            ))?;
+                            let (prefix, value) = result.get_prefix_and_value(v);
+                            let data = result.route_deserialization(
+                                inner_rules.next().ok_or(DBError::unexpected_parsing())?,
+                            )?;
+                            result.push_pair(prefix, value, data)?;
                         }
                         _ => {}
                     }
@@ -146,6 +157,12 @@ pub trait Desereilize {
 
     fn push(&mut self, data: (Primitive, Item)) -> Result<bool, DBError>;
 
+    /// Pushes the top-level pair by the prefix and the value of its key
+    fn push_pair(&mut self, prefix: String, value: String, data: Item) -> Result<bool, DBError> {
+        let key = self.new_primitive(prefix, value)?;
+        self.push((key, data))
+    }
+
     fn new_modifier(prefix: String, data: Item) -> Result<ModifierItem, DBError> {
         Ok(ModifierItem::new(prefix, data)?)
     }
@@ -6,14 +6,17 @@ from annadb.data_types.primitive import UTS
 
 @pytest.fixture(autouse=True)
 def rm_db(conn):
-    conn.send_query(
+    resp = conn.send_query(
         """
     collection|test|:delete;
     collection|test2|:delete;
     collection|test_big|:delete;
     collection|_internal|:delete;
+    collection|_policies|:delete;
+    collection|_procedures|:delete;
     """
     )
+    assert type(resp) != str, resp
 
 
 @pytest.fixture
@@ -0,0 +1,145 @@
+class TestAssert:
+    def test_count(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                assert(count(n|5|)),
+                update[
+                    set{value|name|:s|even|}
+                ]
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 5
+
+    def test_count_collection(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:assert(count(n|10|))
+            """
+        )
+        assert resp[0]["meta"]["count"] == 10
+
+        resp = conn.send_query(
+            """
+            collection|test2|:assert(count(n|0|))
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+    def test_count_failed(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|test_1|}
+                ],
+                assert(count(n|0|))
+            ]
+            """
+        )
+        assert resp == {
+            "error": "Assertion failed: expected count 0, found 1",
+            "step": 0,
+            "query": 1,
+        }
+
+    def test_values(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                assert(eq{value|smth|:s|TEST|}),
+                project{
+                    s|num|:value|num|
+                }
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 5
+        for v in resp[0]["data"].values():
+            assert v["num"] % 2 == 0
+
+    def test_values_failed(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                assert(lt{value|num|:n|9|})
+            ]
+            """
+        )
+        assert resp["error"].startswith("Assertion failed: object ")
+        assert resp["error"].endswith(" does not match")
+        assert resp["step"] == 0
+        assert resp["query"] == 1
+
+    def test_aborts_transaction(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|name|:s|changed|}
+                ]
+            ];
+            collection|test2|:insert[
+                s|foo|
+            ];
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|changed|}
+                ],
+                assert(count(n|0|))
+            ];
+            """
+        )
+        assert resp == {
+            "error": "Assertion failed: expected count 0, found 10",
+            "step": 2,
+            "query": 1,
+        }
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|changed|}
+                ],
+                count
+            ];
+            collection|test2|:count;
+            """
+        )
+        assert resp[0]["data"] == 0
+        assert resp[1]["data"] == 0
+
+    def test_step_counts_every_item(self, conn, objects):
+        resp = conn.send_query(
+            """
+            savepoint:s|before|;
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|name|:s|changed|}
+                ]
+            ];
+            rollback_to:s|before|;
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|changed|}
+                ],
+                assert(count(n|1|))
+            ];
+            """
+        )
+        assert resp == {
+            "error": "Assertion failed: expected count 1, found 0",
+            "step": 3,
+            "query": 1,
+        }
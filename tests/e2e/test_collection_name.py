@@ -8,3 +8,29 @@ class TestCollectionName:
         res = conn.send_query(query_insert)
 
         assert res == "Invalid collection name: _test"
+
+    def test_reserved_name(self, conn):
+        for name in ["count", "param", "ref", "version"]:
+            res = conn.send_query(
+                f"""
+                collection|{name}|: insert[
+                    s|foo|,
+                ]
+                """
+            )
+            assert res == f"Invalid collection name: {name}"
+
+    def test_transaction_item_name(self, conn):
+        for name in ["more", "close", "call", "savepoint", "prepare"]:
+            resp = conn.send_query(
+                f"""
+                collection|{name}|: insert[
+                    s|foo|,
+                ];
+                collection|{name}|:get[
+                    ref|$0.ids.0|
+                ];
+                collection|{name}|:delete;
+                """
+            )
+            assert list(resp[1]["data"].values()) == ["foo"]
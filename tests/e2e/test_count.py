@@ -0,0 +1,64 @@
+class TestCount:
+    def test_count_all(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:count
+            """
+        )
+        assert resp[0]["data"] == 10
+        assert resp[0]["meta"]["count"] == 10
+
+    def test_count_found(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    gt{value|num|:n|4|}
+                ],
+                count
+            ]
+            """
+        )
+        assert resp[0]["data"] == 5
+
+    def test_count_is_last(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                count,
+                limit(n|1|)
+            ]
+            """
+        )
+        assert resp == "Limit query is unavailable"
+
+
+class TestDistinct:
+    def test_distinct(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                distinct(value|is_even|)
+            ]
+            """
+        )
+        assert set(resp[0]["data"]) == {True, False}
+        assert resp[0]["meta"]["count"] == 2
+
+    def test_distinct_wildcard(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:distinct(value|l.*|)
+            """
+        )
+        assert resp[0]["data"] == [9, 8, 7, 6, 5, 4, 3]
+
+    def test_distinct_skips_containers(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:distinct(value|blink|)
+            """
+        )
+        assert resp[0]["data"] == ["test"]
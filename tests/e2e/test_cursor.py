@@ -0,0 +1,128 @@
+class TestCursor:
+    def open(self, conn, size=3):
+        return conn.send_query(
+            f"""
+            collection|test|:q[
+                find[],
+                sort[asc(value|num|)],
+                cursor(n|{size}|),
+                project{{
+                    s|num|:value|num|
+                }}
+            ]
+            """
+        )
+
+    def more(self, conn, cursor):
+        return conn.send_query(
+            f"""
+            more:s|{cursor}|;
+            """
+        )
+
+    def test_pages(self, conn, objects):
+        resp = self.open(conn)
+        assert [v["num"] for v in resp[0]["data"].values()] == [0, 1, 2]
+        assert resp[0]["meta"]["count"] == 3
+        cursor = resp[0]["meta"]["cursor"]
+
+        resp = self.more(conn, cursor)
+        assert [v["num"] for v in resp[0]["data"].values()] == [3, 4, 5]
+        assert resp[0]["meta"]["cursor"] == cursor
+
+        resp = self.more(conn, cursor)
+        assert [v["num"] for v in resp[0]["data"].values()] == [6, 7, 8]
+
+        resp = self.more(conn, cursor)
+        assert [v["num"] for v in resp[0]["data"].values()] == [9]
+        assert resp[0]["meta"]["cursor"] is None
+
+        resp = self.more(conn, cursor)
+        assert resp == f"Cursor {cursor} does not exist"
+
+    def test_single_batch(self, conn, objects):
+        resp = self.open(conn, 10)
+        assert len(resp[0]["data"]) == 10
+        assert resp[0]["meta"]["cursor"] is None
+
+    def test_stable_position(self, conn, objects):
+        resp = self.open(conn)
+        cursor = resp[0]["meta"]["cursor"]
+
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    lt{value|num|:n|5|}
+                ],
+                delete
+            ];
+            collection|test|:q[
+                find[
+                    eq{value|num|:n|7|}
+                ],
+                update[
+                    set{value|num|:n|70|}
+                ]
+            ];
+            """
+        )
+
+        resp = self.more(conn, cursor)
+        assert [v["num"] for v in resp[0]["data"].values()] == [5, 6, 70]
+
+    def test_close(self, conn, objects):
+        resp = self.open(conn)
+        cursor = resp[0]["meta"]["cursor"]
+
+        resp = conn.send_query(
+            f"""
+            close:s|{cursor}|;
+            """
+        )
+        assert len(resp) == 0
+
+        resp = self.more(conn, cursor)
+        assert resp == f"Cursor {cursor} does not exist"
+
+    def test_failed_transaction(self, conn, objects):
+        resp = self.open(conn)
+        cursor = resp[0]["meta"]["cursor"]
+
+        resp = conn.send_query(
+            f"""
+            more:s|{cursor}|;
+            collection|test|:q[
+                find[],
+                assert(
+                    count(n|0|)
+                )
+            ];
+            """
+        )
+        assert resp == {
+            "error": "Assertion failed: expected count 0, found 10",
+            "step": 1,
+            "query": 1,
+        }
+
+        resp = self.more(conn, cursor)
+        assert [v["num"] for v in resp[0]["data"].values()] == [3, 4, 5]
+
+    def test_wrong_size(self, conn, objects):
+        resp = self.open(conn, 0)
+        assert resp == "Cursor batch size must be a positive integer"
+
+    def test_unavailable(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|num|:value|num|
+                },
+                cursor(n|3|)
+            ]
+            """
+        )
+        assert resp == "Cursor query is unavailable"
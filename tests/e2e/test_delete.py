@@ -71,3 +71,224 @@ class TestDelete:
             """
         )
         assert len(resp[0]["data"]) == primitives["meta"]["count"] - 1
+
+
+class TestDeletePolicies:
+    def setup_links(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|},
+                m{s|name|:s|bob|},
+            ]
+            """
+        )
+        ann, bob = resp[0]["data"]
+        conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|title|:s|first|,s|author|:{to_str(ann)}}},
+                m{{s|title|:s|second|,s|author|:{to_str(bob)}}},
+            ]
+            """
+        )
+        return ann, bob
+
+    def test_without_policy(self, conn):
+        ann, bob = self.setup_links(conn)
+        conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+
+    def test_restrict(self, conn):
+        ann, bob = self.setup_links(conn)
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                value|author|:s|restrict|
+            }
+            """
+        )
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        assert resp == "Delete is restricted: test objects link to it by author"
+
+        resp = conn.send_query(
+            """
+            collection|test2|:find[]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+
+    def test_cascade(self, conn):
+        ann, bob = self.setup_links(conn)
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                root:s|cascade|
+            }
+            """
+        )
+        conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for k, v in resp[0]["data"].items():
+            assert v["title"] == "second"
+
+    def test_set_null(self, conn):
+        ann, bob = self.setup_links(conn)
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                value|author|:s|set_null|
+            }
+            """
+        )
+        conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                eq{value|title|:s|first|}
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for k, v in resp[0]["data"].items():
+            assert v["author"] is None
+
+    def test_most_specific_policy(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|},
+                m{s|name|:s|bob|},
+            ];
+            collection|test|:insert[
+                m{s|authors|:v[
+                    m{s|id|:ref|$0.ids.0|},
+                    m{s|id|:ref|$0.ids.1|},
+                ]}
+            ];
+            """
+        )
+        ann, bob = resp[0]["data"]
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                value|authors.*.id|:s|cascade|,
+                value|authors.0.id|:s|restrict|
+            }
+            """
+        )
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        assert resp == "Delete is restricted: test objects link to it by authors.0.id"
+
+        conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(bob)}],
+                delete
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+    def test_policies_are_reserved(self, conn):
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                value|author|:s|restrict|
+            }
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|_policies|:insert[
+                m{s|collection|:s|test|}
+            ]
+            """
+        )
+        assert resp == "Collection _policies is reserved"
+
+        resp = conn.send_query(
+            """
+            collection|_policies|:q[
+                find[],
+                delete
+            ]
+            """
+        )
+        assert resp == "Collection _policies is reserved"
+
+    def test_drop_policies(self, conn):
+        ann, bob = self.setup_links(conn)
+        conn.send_query(
+            """
+            collection|test|:on_delete{
+                value|author|:s|restrict|
+            }
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|_policies|:delete
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                delete
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
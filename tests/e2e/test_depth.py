@@ -0,0 +1,146 @@
+from annadb.data_types.primitive import Link
+from annadb.dump import to_str
+
+
+class TestDepth:
+    def insert_friends(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{
+                    s|name|:s|ann|
+                },
+                m{
+                    s|name|:s|bob|
+                },
+                m{
+                    s|name|:s|carl|
+                }
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$0.ids.0|
+                ],
+                update[
+                    set{value|friend|:ref|$0.ids.1|}
+                ]
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$0.ids.1|
+                ],
+                update[
+                    set{value|friend|:ref|$0.ids.2|}
+                ]
+            ];
+            """
+        )
+        return resp[0]["data"]
+
+    def find_ann(self, conn, depth):
+        return conn.send_query(
+            f"""
+            collection|test2|:q[
+                find[
+                    eq{{value|name|:s|ann|}}
+                ],
+                depth(n|{depth}|)
+            ]
+            """
+        )
+
+    def test_depth_zero(self, conn):
+        ann, bob, _ = self.insert_friends(conn)
+        resp = self.find_ann(conn, 0)
+        assert resp[0]["data"][ann]["name"] == "ann"
+        assert resp[0]["data"][ann]["friend"] == bob
+        assert type(resp[0]["data"][ann]["friend"]) == Link
+
+    def test_depth_one(self, conn):
+        ann, _, carl = self.insert_friends(conn)
+        resp = self.find_ann(conn, 1)
+        friend = resp[0]["data"][ann]["friend"]
+        assert friend["name"] == "bob"
+        assert friend["friend"] == carl
+
+        resp = self.find_ann(conn, 2)
+        friend = resp[0]["data"][ann]["friend"]
+        assert friend["friend"]["name"] == "carl"
+
+    def test_internal_values(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{
+                    s|d|:m{
+                        s|l|:v[n|1|, n|2|]
+                    }
+                }
+            ]
+            """
+        )
+        first = resp[0]["data"][0]
+
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[],
+                depth(n|0|)
+            ]
+            """
+        )
+        assert resp[0]["data"][first] == {"d": {"l": [1, 2]}}
+
+    def test_cycle(self, conn):
+        ann, bob, _ = self.insert_friends(conn)
+        conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[
+                    {to_str(bob)}
+                ],
+                update[
+                    set{{value|friend|:{to_str(ann)}}}
+                ]
+            ]
+            """
+        )
+
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[
+                    eq{value|name|:s|ann|}
+                ]
+            ]
+            """
+        )
+        friend = resp[0]["data"][ann]["friend"]
+        assert friend["name"] == "bob"
+        assert friend["friend"] == ann
+        assert type(friend["friend"]) == Link
+
+    def test_with_project(self, conn):
+        ann, _, carl = self.insert_friends(conn)
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[
+                    eq{value|name|:s|ann|}
+                ],
+                depth(n|1|),
+                project{
+                    s|friend|:keep
+                }
+            ]
+            """
+        )
+        assert resp[0]["data"][ann]["friend"]["name"] == "bob"
+        assert resp[0]["data"][ann]["friend"]["friend"] == carl
+
+    def test_out_of_range(self, conn):
+        resp = self.find_ann(conn, -1)
+        assert resp == "Depth is out of range"
+
+        resp = self.find_ann(conn, 2000)
+        assert resp == "Depth is out of range"
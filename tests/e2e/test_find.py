@@ -302,6 +302,506 @@ class TestFindOr:
             assert v["num"] > 7 or v["is_even"] == True
 
 
+class TestFindIn:
+    def test_in_root(self, conn, primitives):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                in{root:v[n|1|,n|3|,s|test2|]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 3
+        for k, v in resp[0]["data"].items():
+            assert v in [1, 3, "test2"]
+
+    def test_in(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                in{value|num|:v[n|1|,n|3|,n|100|]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 2
+        for k, v in resp[0]["data"].items():
+            assert v["num"] in [1, 3]
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                not(in{value|num|:v[n|1|,n|3|,n|100|]})
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 8
+
+    def test_in_inside_and_or(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                and[
+                    in{value|num|:v[n|1|,n|2|,n|3|,n|4|]},
+                    eq{value|is_even|:b|true|}
+                ]
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 2
+        for k, v in resp[0]["data"].items():
+            assert v["num"] in [2, 4]
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                or[
+                    in{value|num|:v[n|1|,n|2|]},
+                    gt{value|num|:n|7|}
+                ]
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 4
+
+    def test_in_wrong_value(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                in{value|num|:n|1|}
+            ]
+            """
+        )
+        assert resp == "In operator can contain only vectors"
+
+
+class TestFindNin:
+    def test_nin_root(self, conn, primitives):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                nin{root:v[n|1|,n|3|,s|test2|]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == primitives["meta"]["count"] - 3
+        for k, v in resp[0]["data"].items():
+            assert v not in [1, 3, "test2"]
+
+    def test_nin(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                nin{value|num|:v[n|1|,n|3|,n|100|]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 8
+        for k, v in resp[0]["data"].items():
+            assert v["num"] not in [1, 3]
+
+    def test_nin_field_not_exists(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                nin{value|blink.smth|:v[n|1|,n|3|]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 8
+
+
+class TestFindExists:
+    def test_exists(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                exists{value|blink.smth|:b|true|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert "smth" in v["blink"]
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                exists{value|blink2.a|:b|false|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert "a" not in v["blink2"]
+
+    def test_exists_vector_index(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                exists{value|l.6|:b|true|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                exists{value|l.7|:b|true|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 0
+
+
+class TestFindType:
+    def test_type_root(self, conn, primitives):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                type{root:s|n|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert isinstance(v, (int, float))
+
+    def test_type(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                type{value|blink|:s|s|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert v["blink"] == "test"
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                or[
+                    type{value|blink|:s|m|},
+                    type{value|num|:s|s|}
+                ]
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert isinstance(v["blink"], dict)
+
+    def test_type_wrong_value(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                type{value|num|:n|1|}
+            ]
+            """
+        )
+        assert resp == "Type operator can contain only strings with type prefixes"
+
+
+class TestFindString:
+    def test_starts_with(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                starts_with{value|name|:s|test_|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                starts_with{value|smth|:s|test|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 0
+
+    def test_contains(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                contains{value|name|:s|t_3|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for k, v in resp[0]["data"].items():
+            assert v["name"] == "test_3"
+
+    def test_ieq(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                ieq{value|smth|:s|test|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+    def test_regex(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                regex{value|name|:s|^test_[1-3]$|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 3
+        for k, v in resp[0]["data"].items():
+            assert v["name"] in ["test_1", "test_2", "test_3"]
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                or[
+                    regex{value|name|:s|(?i)^TEST_0$|},
+                    starts_with{value|name|:s|test_9|}
+                ]
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 2
+
+    def test_regex_too_big(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                regex{value|name|:s|(.{100}){100}|}
+            ]
+            """
+        )
+        assert resp.startswith("Invalid regex pattern")
+
+
+class TestFindArray:
+    def test_contains(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                contains{value|l|:n|9|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                contains{value|l|:n|10|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 0
+
+    def test_any(self, conn):
+        conn.send_query(
+            """
+            collection|test|:insert[
+                m{s|orders|:v[m{s|total|:n|50|},m{s|total|:n|150|}]},
+                m{s|orders|:v[m{s|total|:n|10|}]},
+                m{s|orders|:v[]},
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                any{value|orders|:gt{value|total|:n|100|}}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for k, v in resp[0]["data"].items():
+            assert v["orders"][1]["total"] == 150
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                any{value|orders|:and[gt{value|total|:n|5|},lt{value|total|:n|60|}]}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 2
+
+    def test_any_root(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                any{value|l|:lt{root:n|4|}}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+    def test_size(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                size{value|l|:n|7|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                size{value|blink2|:gt{root:n|0|}}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for k, v in resp[0]["data"].items():
+            assert v["is_even"] == True
+
+
+class TestFindWildcard:
+    def test_vector_wildcard(self, conn):
+        conn.send_query(
+            """
+            collection|test|:insert[
+                m{s|orders|:v[m{s|price|:n|5|},m{s|price|:n|150|}]},
+                m{s|orders|:v[m{s|price|:n|10|}]},
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                gt{value|orders.*.price|:n|100|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                lt{value|orders.*.price|:n|100|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 2
+
+    def test_map_wildcard(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                eq{value|d.*|:n|3|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for k, v in resp[0]["data"].items():
+            assert v["d"]["smth"] == 3
+
+    def test_last(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                eq{value|l.$last|:n|3|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 10
+
+
+class TestFindVia:
+    def test_via_link(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|,s|age|:n|30|},
+                m{s|name|:s|bob|,s|age|:n|10|},
+            ]
+            """
+        )
+        ann, bob = resp[0]["data"]
+
+        conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|title|:s|first|,s|author|:{to_str(ann)}}},
+                m{{s|title|:s|second|,s|author|:{to_str(bob)}}},
+                m{{s|title|:s|third|,s|authors|:v[{to_str(ann)},{to_str(bob)}]}},
+            ]
+            """
+        )
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                via{value|author|:gt{value|age|:n|18|}}
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for k, v in resp[0]["data"].items():
+            assert v["title"] == "first"
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                via{value|authors|:eq{value|name|:s|bob|}}
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for k, v in resp[0]["data"].items():
+            assert v["title"] == "third"
+
+    def test_via_depth(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test|:insert[
+                m{s|name|:s|boss|}
+            ]
+            """
+        )
+        boss = resp[0]["data"][0]
+        resp = conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|name|:s|manager|,s|reports_to|:{to_str(boss)}}}
+            ]
+            """
+        )
+        manager = resp[0]["data"][0]
+        conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|name|:s|worker|,s|reports_to|:{to_str(manager)}}}
+            ]
+            """
+        )
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                via{value|reports_to|:eq{value|name|:s|boss|}}
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                via{value|reports_to|:eq{value|name|:s|boss|},s|depth|:n|2|}
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+
+
 class TestNot:
     def test_not_root(self, conn, primitives):
         resp = conn.send_query(
@@ -0,0 +1,115 @@
+class TestGroup:
+    def test_group_by_path(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                sort[asc(value|num|)],
+                group{
+                    value|is_even|:m{
+                        s|count|:count,
+                        s|sum|:sum(value|num|),
+                        s|avg|:avg(value|num|),
+                        s|min|:min(value|num|),
+                        s|max|:max(value|num|),
+                        s|names|:push(value|name|),
+                        s|first|:first(value|name|),
+                        s|last|:last(value|name|),
+                    }
+                }
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+        groups = {v["is_even"]: v for v in resp[0]["data"]}
+
+        assert groups[True]["count"] == 5
+        assert groups[True]["sum"] == 20
+        assert groups[True]["avg"] == 4
+        assert groups[True]["min"] == 0
+        assert groups[True]["max"] == 8
+        assert groups[True]["names"] == [f"test_{i}" for i in range(0, 10, 2)]
+        assert groups[True]["first"] == "test_0"
+        assert groups[True]["last"] == "test_8"
+
+        assert groups[False]["count"] == 5
+        assert groups[False]["sum"] == 25
+        assert groups[False]["first"] == "test_1"
+        assert groups[False]["last"] == "test_9"
+
+    def test_group_by_map(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                group{
+                    value|blink2|:m{
+                        s|count|:count,
+                    }
+                }
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+        for v in resp[0]["data"]:
+            assert v["count"] == 5
+
+    def test_group_all(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    gt{value|num|:n|4|}
+                ],
+                group{
+                    null:m{
+                        s|count|:count,
+                        s|sum|:sum(value|num|),
+                    }
+                }
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        assert resp[0]["data"][0]["count"] == 5
+        assert resp[0]["data"][0]["sum"] == 35
+
+    def test_group_is_last(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                group{
+                    value|is_even|:m{
+                        s|count|:count,
+                    }
+                },
+                find[]
+            ]
+            """
+        )
+        assert resp == "Find query is unavailable"
+
+    def test_min_max_mixed_types(self, conn):
+        conn.send_query(
+            """
+            collection|test|:insert[
+                m{s|v|:s|a|},
+                m{s|v|:n|1|},
+                m{s|v|:null}
+            ]
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                group{
+                    null:m{
+                        s|min|:min(value|v|),
+                    }
+                }
+            ]
+            """
+        )
+        assert resp == "Min and max accumulators support values of the same type"
@@ -240,3 +240,103 @@ class TestInsertMany:
         resp_get = conn.send_query(query_get)
         for i in resp_get[0]["data"].values():
             assert i in [1, "2", True, [101, 102, 103]]
+
+
+class TestInsertWithIds:
+    ID_1 = "7b6a3b4e-1c1f-4a43-9d5a-5d0f2b1a1111"
+    ID_2 = "7b6a3b4e-1c1f-4a43-9d5a-5d0f2b1a2222"
+
+    def test_insert(self, conn):
+        resp = conn.send_query(
+            f"""
+            collection|test|: insert{{
+                test|{self.ID_1}|: m{{s|name|: s|foo|}},
+                test|{self.ID_2}|: n|1|,
+            }}
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+        assert {str(i.value) for i in resp[0]["data"]} == {self.ID_1, self.ID_2}
+
+        resp = conn.send_query(
+            f"""
+            collection|test|: get[
+                test|{self.ID_1}|
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v == {"name": "foo"}
+
+    def test_conflict_error(self, conn):
+        query = f"""
+            collection|test|: insert{{
+                test|{self.ID_1}|: n|1|,
+            }}
+            """
+        conn.send_query(query)
+        resp = conn.send_query(query)
+        assert resp == f"Object with id {self.ID_1} already exists"
+
+    def test_conflict_skip(self, conn):
+        conn.send_query(
+            f"""
+            collection|test|: insert{{
+                test|{self.ID_1}|: n|1|,
+            }}
+            """
+        )
+        resp = conn.send_query(
+            f"""
+            collection|test|: insert{{
+                test|{self.ID_1}|: n|2|,
+                test|{self.ID_2}|: n|3|,
+                s|on_conflict|: s|skip|,
+            }}
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        assert str(resp[0]["data"][0].value) == self.ID_2
+
+        resp = conn.send_query(
+            f"""
+            collection|test|: get[
+                test|{self.ID_1}|
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v == 1
+
+    def test_other_collection(self, conn):
+        resp = conn.send_query(
+            f"""
+            collection|test|: insert{{
+                test2|{self.ID_1}|: n|1|,
+            }}
+            """
+        )
+        assert resp == "Inserted ids must belong to the queried collection"
+
+
+class TestIdempotentInsert:
+    def test_retry(self, conn):
+        query = """
+            idempotency_key: s|insert-retry|;
+            collection|test|: insert[
+                n|1|,
+            ];
+            """
+        resp_1 = conn.send_query(query)
+        resp_2 = conn.send_query(query)
+        assert resp_1[0]["data"][0] == resp_2[0]["data"][0]
+
+        resp = conn.send_query(
+            """
+            collection|test|: q[
+                find[],
+                count
+            ]
+            """
+        )
+        assert resp[0]["data"] == 1
@@ -0,0 +1,188 @@
+class TestPrepared:
+    def prepare(self, conn):
+        conn.send_query(
+            """
+            prepare|find_by_num|:template{
+                collection|test|:q[
+                    find[
+                        eq{value|num|:param|num:n|}
+                    ],
+                    project{
+                        s|name|:value|name|
+                    }
+                ]
+            };
+            prepare|rename|:template{
+                collection|test|:q[
+                    find[
+                        eq{value|name|:param|old:s|}
+                    ],
+                    update[
+                        set{value|name|:param|new:s|}
+                    ]
+                ]
+            };
+            """
+        )
+
+    def test_execute(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for v in resp[0]["data"].values():
+            assert v["name"] == "test_3"
+
+    def test_execute_many(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|rename|:m{
+                s|old|:s|test_3|,
+                s|new|:s|renamed|
+            };
+            execute|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for v in resp[1]["data"].values():
+            assert v["name"] == "renamed"
+
+    def test_prepare_and_execute(self, conn, objects):
+        resp = conn.send_query(
+            """
+            prepare|page|:template{
+                collection|test|:q[
+                    find[],
+                    sort[
+                        asc(value|num|)
+                    ],
+                    offset(param|skip:n|),
+                    limit(param|size:n|),
+                    project{
+                        s|num|:value|num|
+                    }
+                ]
+            };
+            execute|page|:m{
+                s|skip|:n|2|,
+                s|size|:n|3|
+            };
+            """
+        )
+        assert [v["num"] for v in resp[0]["data"].values()] == [2, 3, 4]
+
+    def test_injection(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|rename|:m{
+                s|old|:s|test_1|,
+                s|new|:s|x]]; collection: delete;|
+            };
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+
+        resp = conn.send_query(
+            """
+            execute|find_by_num|:m{
+                s|num|:n|1|
+            };
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v["name"] == "x]]; collection: delete;"
+
+        resp = conn.send_query(
+            """
+            collection|test|:count
+            """
+        )
+        assert resp[0]["data"] == 10
+
+    def test_wrong_type(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|find_by_num|:m{
+                s|num|:s|3|
+            };
+            """
+        )
+        assert resp == "Parameter num must have the n prefix"
+
+    def test_missing_parameter(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|rename|:m{
+                s|old|:s|test_1|
+            };
+            """
+        )
+        assert resp == "Parameter new is missing"
+
+    def test_unexpected_parameter(self, conn, objects):
+        self.prepare(conn)
+        resp = conn.send_query(
+            """
+            execute|find_by_num|:m{
+                s|num|:n|3|,
+                s|other|:n|3|
+            };
+            """
+        )
+        assert resp == "Unexpected parameter other"
+
+    def test_unknown_query(self, conn):
+        resp = conn.send_query(
+            """
+            execute|unknown|:m{};
+            """
+        )
+        assert resp == "Prepared query unknown does not exist"
+
+    def test_parameters_outside_of_template(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                eq{value|num|:param|num:n|}
+            ];
+            """
+        )
+        assert resp == "Parameters can be used only in prepared queries"
+
+    def test_param_as_key(self, conn, objects):
+        resp = conn.send_query(
+            """
+            prepare|find_by_field|:template{
+                collection|test|:find[
+                    eq{param|field:value|:param|name:s|}
+                ]
+            };
+            execute|find_by_field|:m{
+                s|field|:value|name|,
+                s|name|:s|test_3|
+            };
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for v in resp[0]["data"].values():
+            assert v["num"] == 3
+
+        resp = conn.send_query(
+            """
+            execute|find_by_field|:m{
+                s|name|:s|test_3|
+            };
+            """
+        )
+        assert resp == "Parameter field is missing"
@@ -0,0 +1,269 @@
+class TestProcedures:
+    def define(self, conn):
+        conn.send_query(
+            """
+            procedure|find_by_num|:template{
+                collection|test|:q[
+                    find[
+                        eq{value|num|:param|num:n|}
+                    ],
+                    project{
+                        s|name|:value|name|
+                    }
+                ]
+            };
+            procedure|rename|:template{
+                collection|test|:q[
+                    find[
+                        eq{value|name|:param|old:s|}
+                    ],
+                    update[
+                        set{value|name|:param|new:s|}
+                    ]
+                ],
+                collection|test|:q[
+                    find[
+                        eq{value|name|:param|new:s|}
+                    ],
+                    assert(
+                        count(n|1|)
+                    )
+                ]
+            };
+            """
+        )
+
+    def test_call(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+        for v in resp[0]["data"].values():
+            assert v["name"] == "test_3"
+
+    def test_call_many_steps(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            call|rename|:m{
+                s|old|:s|test_3|,
+                s|new|:s|renamed|
+            };
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for v in resp[2]["data"].values():
+            assert v["name"] == "renamed"
+
+    def test_atomicity(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            call|rename|:m{
+                s|old|:s|test_3|,
+                s|new|:s|test_4|
+            };
+            """
+        )
+        assert resp == {
+            "error": "Assertion failed: expected count 1, found 2",
+            "step": 0,
+            "query": 1,
+        }
+
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v["name"] == "test_3"
+
+    def test_define_and_call(self, conn, objects):
+        resp = conn.send_query(
+            """
+            procedure|total|:template{
+                collection|test|:count
+            };
+            call|total|:m{};
+            """
+        )
+        assert resp[0]["data"] == 10
+
+    def test_new_version(self, conn, objects):
+        self.define(conn)
+        conn.send_query(
+            """
+            procedure|find_by_num:2|:template{
+                collection|test|:q[
+                    find[
+                        eq{value|num|:param|num:n|}
+                    ],
+                    project{
+                        s|num|:value|num|
+                    }
+                ]
+            };
+            """
+        )
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            call|find_by_num:1|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v == {"num": 3}
+        for v in resp[1]["data"].values():
+            assert v == {"name": "test_3"}
+
+    def test_version_conflict(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            procedure|find_by_num:1|:template{
+                collection|test|:count
+            };
+            """
+        )
+        assert resp == "Procedure find_by_num has version 1"
+
+    def test_unknown_version(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            call|find_by_num:2|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert resp == "Procedure find_by_num version 2 does not exist"
+
+    def test_unknown_procedure(self, conn):
+        resp = conn.send_query(
+            """
+            call|unknown|:m{};
+            """
+        )
+        assert resp == "Procedure unknown does not exist"
+
+    def test_wrong_type(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:s|3|
+            };
+            """
+        )
+        assert resp == "Parameter num must have the n prefix"
+
+    def test_nested_call(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            procedure|nested|:template{
+                call|find_by_num|:m{
+                    s|num|:n|3|
+                }
+            };
+            """
+        )
+        assert resp == "Templates can contain only steps, savepoints and rollbacks"
+
+    def test_procedures_are_reserved(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            collection|_procedures|:insert[
+                m{s|name|:s|find_by_num|}
+            ];
+            """
+        )
+        assert resp == "Collection _procedures is reserved"
+
+        resp = conn.send_query(
+            """
+            procedure|drop_procedures|:template{
+                collection|_procedures|:q[
+                    find[],
+                    delete
+                ]
+            };
+            call|drop_procedures|:m{};
+            """
+        )
+        assert resp == "Collection _procedures is reserved"
+
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert len(resp[0]["data"]) == 1
+
+    def test_drop_procedures(self, conn, objects):
+        self.define(conn)
+        resp = conn.send_query(
+            """
+            collection|_procedures|:delete;
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+        resp = conn.send_query(
+            """
+            call|find_by_num|:m{
+                s|num|:n|3|
+            };
+            """
+        )
+        assert resp == "Procedure find_by_num does not exist"
+
+    def test_foreign_savepoint(self, conn, objects):
+        resp = conn.send_query(
+            """
+            procedure|discard|:template{
+                rollback_to:s|caller|
+            };
+            """
+        )
+        assert resp == "Savepoint caller is not created by the template"
+
+        resp = conn.send_query(
+            """
+            procedure|retry|:template{
+                savepoint:s|a|,
+                collection|test|:insert[
+                    s|discarded|
+                ],
+                rollback_to:s|a|
+            };
+            collection|test|:insert[
+                s|kept|
+            ];
+            call|retry|:m{};
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:count
+            """
+        )
+        assert resp[0]["data"] == 11
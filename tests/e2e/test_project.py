@@ -1,3 +1,6 @@
+from annadb.dump import to_str
+
+
 class TestProject:
     def test_keep(self, conn, objects):
         resp = conn.send_query(
@@ -90,3 +93,174 @@ class TestProject:
             assert set(v._value.keys()) == {"name", "l"}
             assert v["name"] == ["TEST"]
             assert v["l"] == ["TEST", 8, 7]
+
+    def test_wildcard_path(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                sort[asc(value|name|)],
+                project{
+                    s|values|:value|l.*|,
+                    s|last|:value|l.$last|,
+                }
+            ]
+            """
+        )
+        for i, (k, v) in enumerate(resp[0]["data"].items()):
+            assert v["values"] == [9, 8, 7, 6, 5, 4, 3]
+            assert v["last"] == 3
+
+    def test_exclude(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                sort[asc(value|name|)],
+                project{
+                    s|blink|:exclude,
+                    s|blink2|:exclude,
+                    s|ts|:exclude,
+                    s|l|:exclude,
+                    s|d|:m{
+                        s|smth2|:exclude
+                    },
+                    s|renamed|:value|smth|,
+                    s|smth|:exclude
+                }
+            ]
+            """
+        )
+        for i, (k, v) in enumerate(resp[0]["data"].items()):
+            assert v == {
+                "name": f"test_{i}",
+                "num": i,
+                "is_even": i % 2 == 0,
+                "d": {"smth": i},
+                "renamed": "TEST",
+            }
+
+    def test_computed(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                sort[asc(value|name|)],
+                project{
+                    s|title|:concat[value|name|, s|-|, value|num|],
+                    s|sum|:add[value|num|, value|d.smth2|, n|1|],
+                    s|diff|:sub[value|num|, n|1|],
+                    s|product|:mul[value|num|, n|3|],
+                    s|half|:div[value|num|, n|2|],
+                    s|missing|:add[value|nothing|, n|1|],
+                    s|parity|:cond[
+                        eq{value|is_even|:b|true|},
+                        s|even|,
+                        s|odd|
+                    ],
+                    s|flag|:cond[value|is_even|, n|1|, n|0|]
+                }
+            ]
+            """
+        )
+        for i, (k, v) in enumerate(resp[0]["data"].items()):
+            assert v == {
+                "title": f"test_{i}-{i}",
+                "sum": i + 3,
+                "diff": i - 1,
+                "product": i * 3,
+                "half": i / 2,
+                "missing": None,
+                "parity": "even" if i % 2 == 0 else "odd",
+                "flag": 1 if i % 2 == 0 else 0,
+            }
+
+    def test_computed_errors(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|x|:div[value|num|, n|0|]
+                }
+            ]
+            """
+        )
+        assert resp == "Division by zero"
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|x|:add[value|name|, n|1|]
+                }
+            ]
+            """
+        )
+        assert resp == "Arithmetic expressions support only numbers"
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|x|:concat[value|d|]
+                }
+            ]
+            """
+        )
+        assert resp == "Concat supports only strings and numbers"
+
+    def test_link_id_and_fetch_depth(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|}
+            ]
+            """
+        )
+        ann = resp[0]["data"][0]
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:insert[
+                m{{s|name|:s|bob|,s|friend|:{to_str(ann)}}}
+            ]
+            """
+        )
+        bob = resp[0]["data"][0]
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|author|:{to_str(bob)},s|d|:m{{s|a|:n|1|}}}}
+            ]
+            """
+        )
+        first = resp[0]["data"][0]
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|id|:link_id,
+                    s|author_id|:link_id|author|,
+                    s|friend_id|:link_id|author.friend|,
+                    s|d|:fetch[value|d|, n|0|],
+                    s|author0|:fetch[value|author|, n|0|],
+                    s|author1|:fetch[value|author|, n|1|],
+                    s|author2|:fetch[value|author|, n|2|]
+                }
+            ]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["id"] == first
+            assert v["author_id"] == bob
+            assert v["friend_id"] == ann
+            assert v["d"] == {"a": 1}
+            assert v["author0"] == bob
+            assert v["author1"] == {"name": "bob", "friend": ann}
+            assert v["author2"] == {"name": "bob", "friend": {"name": "ann"}}
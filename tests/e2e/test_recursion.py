@@ -33,4 +33,4 @@ class TestRecursion:
         collection|test|: find[]
         """
         res = conn.send_query(result_query)
-        assert res == "Fetch recursion error"
+        assert res[0]["data"] == {id_1: id_1, id_2: id_2}
@@ -0,0 +1,116 @@
+from annadb.dump import to_str
+
+
+class TestReferencedBy:
+    def test_referenced_by(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|},
+                m{s|name|:s|bob|},
+            ]
+            """
+        )
+        ann, bob = resp[0]["data"]
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|author|:{to_str(ann)},s|editor|:{to_str(bob)}}},
+                m{{s|author|:{to_str(bob)}}},
+                m{{s|tags|:v[m{{s|by|:{to_str(ann)}}}]}},
+            ]
+            """
+        )
+        first, second, third = resp[0]["data"]
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                referenced_by
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 2
+        data = resp[0]["data"]["test"]
+        assert set(data.keys()) == {"author", "tags.0.by"}
+        assert list(data["author"]) == [first]
+        assert list(data["tags.0.by"]) == [third]
+
+        resp = conn.send_query(
+            """
+            collection|test2|:referenced_by
+            """
+        )
+        assert resp[0]["meta"]["count"] == 3
+        data = resp[0]["data"]["test"]
+        assert set(data.keys()) == {"author", "editor", "tags.0.by"}
+        assert set(data["author"]) == {first, second}
+
+    def test_referenced_by_after_update(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{s|name|:s|ann|},
+                m{s|name|:s|bob|},
+            ]
+            """
+        )
+        ann, bob = resp[0]["data"]
+
+        conn.send_query(
+            f"""
+            collection|test|:insert[
+                m{{s|title|:s|post|,s|author|:{to_str(ann)}}},
+            ]
+            """
+        )
+
+        conn.send_query(
+            f"""
+            collection|test|:q[
+                find[],
+                update[set{{value|author|:{to_str(bob)}}}]
+            ]
+            """
+        )
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(ann)}],
+                referenced_by
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(bob)}],
+                referenced_by
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                delete
+            ]
+            """
+        )
+
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                get[{to_str(bob)}],
+                referenced_by
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
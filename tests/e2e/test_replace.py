@@ -0,0 +1,72 @@
+from annadb.dump import to_str
+
+
+class TestReplace:
+    def test_replace(self, conn, objects):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                replace(m{{
+                    s|name|:s|replaced|,
+                    s|tags|:v[s|a|,s|b|]
+                }})
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for k, v in resp[0]["data"].items():
+            assert k == objects["data"][0]
+            assert v["name"] == "test_0"
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ]
+            ]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert k == objects["data"][0]
+            assert v == {"name": "replaced", "tags": ["a", "b"]}
+
+    def test_replace_found(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                replace(n|1|)
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 5
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{root:n|1|}
+                ],
+                count
+            ]
+            """
+        )
+        assert resp[0]["data"] == 5
+
+    def test_replace_is_last(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                replace(n|1|),
+                find[]
+            ]
+            """
+        )
+        assert resp == "Find query is unavailable"
@@ -0,0 +1,150 @@
+from annadb.data_types.primitive import Link
+
+
+class TestStepResults:
+    def test_insert_link(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test|:insert[
+                m{
+                    s|name|:s|bob|
+                }
+            ];
+            collection|test2|:insert[
+                m{
+                    s|title|:s|hello|,
+                    s|author|:ref|$0.ids.0|
+                }
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$1.ids.0|
+                ],
+                project{
+                    s|author|:value|author.name|
+                }
+            ];
+            """
+        )
+        assert type(resp[1]["data"][0]) == Link
+        assert len(resp[2]["data"]) == 1
+        for v in resp[2]["data"].values():
+            assert v["author"] == "bob"
+
+    def test_get_all_ids(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                project{
+                    s|num|:value|num|
+                }
+            ];
+            collection|test|:q[
+                get[
+                    ref|$0.ids|
+                ],
+                update[
+                    set{value|name|:s|even|}
+                ]
+            ];
+            """
+        )
+        assert resp[1]["meta"]["count"] == 5
+
+        resp = conn.send_query(
+            """
+            collection|test|:find[
+                eq{value|name|:s|even|}
+            ]
+            """
+        )
+        assert len(resp[0]["data"]) == 5
+        for v in resp[0]["data"].values():
+            assert v["is_even"] == True
+
+    def test_find(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                count
+            ];
+            collection|test|:q[
+                find[
+                    lt{value|num|:ref|$0.data|}
+                ],
+                count
+            ];
+            """
+        )
+        assert resp[1]["data"] == 5
+
+    def test_not_available(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:get[
+                ref|$1.ids.0|
+            ];
+            collection|test|:count;
+            """
+        )
+        assert resp == "Step result $1 is not available"
+
+    def test_every_item_is_numbered(self, conn):
+        resp = conn.send_query(
+            """
+            procedure|insert_two|:template{
+                collection|test|:insert[
+                    n|1|
+                ],
+                collection|test|:insert[
+                    n|2|
+                ]
+            };
+            savepoint:s|a|;
+            call|insert_two|:m{};
+            collection|test|:get[
+                ref|$2.ids.0|
+            ];
+            """
+        )
+        assert list(resp[2]["data"].values()) == [2]
+
+    def test_no_result(self, conn):
+        resp = conn.send_query(
+            """
+            savepoint:s|a|;
+            collection|test|:get[
+                ref|$0.ids.0|
+            ];
+            """
+        )
+        assert resp == "Step result $0 is not available"
+
+    def test_out_of_range(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:insert[
+                n|1|
+            ];
+            collection|test|:get[
+                ref|$0.ids.1|
+            ];
+            """
+        )
+        assert resp == "Step result $0.ids.1 does not exist"
+
+    def test_invalid(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test|:get[
+                ref|0.ids|
+            ];
+            """
+        )
+        assert resp == "Invalid step result 0.ids"
@@ -199,3 +199,114 @@ class TestTransactions:
             if v != Deleted():
                 assert v["vec"][0] == 101
                 assert v["foo"] == "bar"
+
+
+class TestSavepoints:
+    def test_rollback_to(self, conn, objects):
+        resp = conn.send_query(
+            """
+            savepoint:s|before_update|;
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|name|:s|changed|}
+                ]
+            ];
+            collection|test2|:insert[
+                s|foo|
+            ];
+            rollback_to:s|before_update|;
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|changed|}
+                ],
+                count
+            ];
+            """
+        )
+        assert len(resp) == 1
+        assert resp[0]["data"] == 0
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|changed|}
+                ],
+                count
+            ];
+            collection|test2|:count;
+            """
+        )
+        assert resp[0]["data"] == 0
+        assert resp[1]["data"] == 0
+
+    def test_partial_rollback(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test2|:insert[
+                s|kept|
+            ];
+            savepoint:s|a|;
+            collection|test2|:insert[
+                s|discarded|
+            ];
+            rollback_to:s|a|;
+            """
+        )
+        resp = conn.send_query(
+            """
+            collection|test2|:find[]
+            """
+        )
+        assert list(resp[0]["data"].values()) == ["kept"]
+
+    def test_rollback_dropped_collection(self, conn, objects):
+        resp = conn.send_query(
+            """
+            savepoint:s|a|;
+            collection|test|:delete;
+            rollback_to:s|a|;
+            collection|test|:count;
+            """
+        )
+        assert resp[0]["data"] == 10
+
+    def test_rollback_discards_results(self, conn):
+        resp = conn.send_query(
+            """
+            savepoint:s|a|;
+            collection|test|:insert[
+                s|discarded|
+            ];
+            rollback_to:s|a|;
+            collection|test|:get[
+                ref|$1.ids.0|
+            ];
+            """
+        )
+        assert resp == "Step result $1 is not available"
+
+        resp = conn.send_query(
+            """
+            savepoint:s|a|;
+            collection|test|:insert[
+                s|discarded|
+            ];
+            rollback_to:s|a|;
+            collection|test|:count;
+            """
+        )
+        assert len(resp) == 1
+        assert resp[0]["data"] == 0
+
+    def test_released_savepoint(self, conn, objects):
+        resp = conn.send_query(
+            """
+            savepoint:s|a|;
+            savepoint:s|b|;
+            rollback_to:s|a|;
+            rollback_to:s|b|;
+            """
+        )
+        assert resp == "Savepoint b does not exist"
@@ -0,0 +1,237 @@
+from annadb.dump import to_str
+
+
+class TestTraverse:
+    def insert_graph(self, conn):
+        # a -> b, c; b -> a, d; c -> d
+        resp = conn.send_query(
+            """
+            collection|test2|:insert[
+                m{
+                    s|name|:s|a|
+                },
+                m{
+                    s|name|:s|b|
+                },
+                m{
+                    s|name|:s|c|
+                },
+                m{
+                    s|name|:s|d|
+                }
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$0.ids.0|
+                ],
+                update[
+                    set{value|friends|:v[ref|$0.ids.1|, ref|$0.ids.2|]}
+                ]
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$0.ids.1|
+                ],
+                update[
+                    set{value|friends|:v[ref|$0.ids.0|, ref|$0.ids.3|]}
+                ]
+            ];
+            collection|test2|:q[
+                get[
+                    ref|$0.ids.2|
+                ],
+                update[
+                    set{value|friends|:v[ref|$0.ids.3|]}
+                ]
+            ];
+            """
+        )
+        return resp[0]["data"]
+
+    def traverse(self, conn, expr, options):
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                find[
+                    eq{{value|name|:s|a|}}
+                ],
+                traverse{{
+                    value|friends|:{expr},
+                    {options}
+                }},
+                sort[asc(value|name|)],
+                project{{
+                    s|name|:keep
+                }}
+            ]
+            """
+        )
+        if type(resp) == str:
+            return resp
+        return [v["name"] for v in resp[0]["data"].values()]
+
+    def shortest_path(self, conn, expr, to, options=""):
+        resp = conn.send_query(
+            f"""
+            collection|test2|:q[
+                find[
+                    eq{{value|name|:s|a|}}
+                ],
+                shortest_path{{
+                    value|friends|:{expr},
+                    s|to|:{to_str(to)},
+                    {options}
+                }},
+                project{{
+                    s|name|:keep
+                }}
+            ]
+            """
+        )
+        if type(resp) == str:
+            return resp
+        return [v["name"] for v in resp[0]["data"].values()]
+
+    def test_one_hop(self, conn):
+        self.insert_graph(conn)
+        assert self.traverse(conn, "b|true|", "") == ["b", "c"]
+
+    def test_hops_range(self, conn):
+        self.insert_graph(conn)
+        assert self.traverse(conn, "b|true|", "s|min|:n|1|, s|max|:n|3|") == [
+            "a",
+            "b",
+            "c",
+            "d",
+        ]
+        assert self.traverse(conn, "b|true|", "s|min|:n|2|, s|max|:n|3|") == [
+            "a",
+            "d",
+        ]
+        assert self.traverse(conn, "b|true|", "s|min|:n|0|, s|max|:n|1|") == [
+            "a",
+            "b",
+            "c",
+        ]
+
+    def test_filter(self, conn):
+        self.insert_graph(conn)
+        assert self.traverse(
+            conn, "neq{value|name|:s|b|}", "s|max|:n|3|"
+        ) == ["c", "d"]
+        assert self.traverse(conn, "eq{value|name|:s|b|}", "s|max|:n|3|") == ["b"]
+
+    def test_cycle(self, conn):
+        self.insert_graph(conn)
+        assert self.traverse(
+            conn, "b|true|", "s|min|:n|2|, s|max|:n|1024|"
+        ) == ["a", "d"]
+
+    def test_whole_collection(self, conn):
+        self.insert_graph(conn)
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                traverse{
+                    value|friends|:b|true|
+                },
+                sort[asc(value|name|)],
+                project{
+                    s|name|:keep
+                }
+            ]
+            """
+        )
+        assert [v["name"] for v in resp[0]["data"].values()] == ["a", "b", "c", "d"]
+
+    def test_linked_start_objects(self, conn):
+        self.insert_graph(conn)
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[
+                    in{value|name|:v[s|a|, s|b|]}
+                ],
+                traverse{
+                    value|friends|:b|true|
+                },
+                sort[asc(value|name|)],
+                project{
+                    s|name|:keep
+                }
+            ]
+            """
+        )
+        assert [v["name"] for v in resp[0]["data"].values()] == ["a", "b", "c", "d"]
+
+    def test_without_links(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                traverse{
+                    value|name|:b|true|
+                }
+            ]
+            """
+        )
+        assert resp[0]["data"] == {}
+
+    def test_errors(self, conn):
+        self.insert_graph(conn)
+        assert (
+            self.traverse(conn, "b|true|", "s|min|:n|2|, s|max|:n|1|")
+            == "Min hops can not be greater than max hops"
+        )
+        assert (
+            self.traverse(conn, "b|true|", "s|max|:n|-1|") == "Hops are out of range"
+        )
+        assert (
+            self.traverse(conn, "b|true|", "s|depth|:n|1|")
+            == "Traverse query supports only min and max options"
+        )
+        assert (
+            self.traverse(conn, "b|true|", "value|x|:b|true|")
+            == "Only one path can be followed"
+        )
+        assert self.traverse(conn, "s|a|", "") == (
+            "Links can be filtered only by find expressions"
+        )
+
+    def test_shortest_path(self, conn):
+        a, b, c, d = self.insert_graph(conn)
+        assert self.shortest_path(conn, "b|true|", d) == ["a", "b", "d"]
+        assert self.shortest_path(conn, "neq{value|name|:s|b|}", d) == ["a", "c", "d"]
+        assert self.shortest_path(conn, "b|true|", a) == ["a"]
+
+    def test_shortest_path_unreachable(self, conn):
+        a, b, c, d = self.insert_graph(conn)
+        assert self.shortest_path(conn, "b|true|", d, "s|max|:n|1|") == []
+        assert self.shortest_path(conn, "eq{value|name|:s|c|}", d) == []
+
+    def test_shortest_path_errors(self, conn):
+        self.insert_graph(conn)
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[],
+                shortest_path{
+                    value|friends|:b|true|,
+                    s|to|:s|d|
+                }
+            ]
+            """
+        )
+        assert resp == "Shortest path target must be a link"
+
+        resp = conn.send_query(
+            """
+            collection|test2|:q[
+                find[],
+                shortest_path{
+                    value|friends|:b|true|
+                }
+            ]
+            """
+        )
+        assert resp == "Shortest path target must be a link"
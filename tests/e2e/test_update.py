@@ -1,3 +1,4 @@
+from annadb.data_types.primitive import UTS
 from annadb.dump import to_str
 
 
@@ -141,8 +142,73 @@ class TestUpdateOther:
             """
         )
         for k, v in resp[0]["data"].items():
-            if "a" in v["blink2"]._value:
+            if v["is_even"]:
                 assert v["blink2"]["a"] == 200
+            else:
+                assert v["blink2"]["a"] == 100
+
+    def test_field_not_exists_intermediate(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|x.y|:n|1|}
+                ]
+            ];
+            """
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{
+                        value|x.y|:n|1|,
+                        s|create_paths|:b|true|
+                    }
+                ]
+            ];
+            """
+        )
+        assert resp[0]["meta"]["count"] == 10
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["x"] == {"y": 1}
+
+    def test_create_paths_through_primitive(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{
+                        value|num.y|:n|1|,
+                        s|create_paths|:b|true|
+                    }
+                ]
+            ];
+            """
+        )
+        assert resp == "Path num.y can not be created"
+
+    def test_inc_not_number(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    inc{value|name|:n|1|}
+                ]
+            ];
+            """
+        )
+        assert resp == "Inc operator supports numbers only"
 
     def test_double_update(self, conn, objects):
         conn.send_query(
@@ -292,3 +358,427 @@ class TestUpdateOther:
         for k, v in resp[0]["data"].items():
             assert v["bar"] == "bazzz"
             assert v["bar_2"] == "bazzz_2"
+
+
+class TestUpdateOperators:
+    def test_mul(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    mul{
+                        value|num|:n|3|,
+                        value|missing|:n|3|
+                    }
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["num"] == int(v["name"][5:]) * 3
+            assert v["missing"] == 0
+
+    def test_min_max(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    min{value|d.smth|:n|5|},
+                    max{value|d.smth2|:n|5|},
+                    max{value|d.missing|:n|5|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["d"]["smth"] == min(v["num"], 5)
+            assert v["d"]["smth2"] == 5
+            assert v["d"]["missing"] == 5
+
+    def test_min_max_type_mismatch(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    min{value|name|:n|5|}
+                ]
+            ];"""
+        )
+        assert resp == "Min and max operators support values of the same type"
+
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["name"].startswith("test_")
+
+    def test_unset(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|is_even|:b|true|}
+                ],
+                update[
+                    unset{
+                        value|smth|:null,
+                        value|d.smth|:null,
+                        value|missing|:null
+                    }
+                ]
+            ];"""
+        )
+        assert resp[0]["meta"]["count"] == 5
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            if v["is_even"]:
+                assert "smth" not in v._value
+                assert v["d"] == {"smth2": 2}
+            else:
+                assert v["smth"] == "TEST"
+
+    def test_unset_root(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    unset{root:null}
+                ]
+            ];"""
+        )
+        assert resp == "Unset operator keys must be paths"
+
+    def test_rename(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    rename{
+                        value|name|:value|title|,
+                        value|d|:value|nested.d|,
+                        s|create_paths|:b|true|
+                    }
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert "name" not in v._value
+            assert "d" not in v._value
+            assert v["title"] == f"test_{v['num']}"
+            assert v["nested"]["d"] == {"smth": v["num"], "smth2": 2}
+
+    def test_rename_conflict(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    rename{value|d|:value|d.smth|}
+                ]
+            ];"""
+        )
+        assert resp == "Rename paths conflict"
+
+    def test_now(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    now{value|ts|:null}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["ts"] != UTS(12345)
+
+
+class TestArrayOperators:
+    def test_push(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    push{
+                        value|l|:n|2|,
+                        value|new_l|:s|a|
+                    }
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [9, 8, 7, 6, 5, 4, 3, 2]
+            assert v["new_l"] == ["a"]
+
+    def test_add_to_set(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    add_to_set{value|l|:n|9|},
+                    add_to_set{value|l|:n|1|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [9, 8, 7, 6, 5, 4, 3, 1]
+
+    def test_pull_by_value(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    pull{value|l|:n|7|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [9, 8, 6, 5, 4, 3]
+
+    def test_pull_by_predicate(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    pull{value|l|:gt{root:n|5|}}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [5, 4, 3]
+
+    def test_pop(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    pop{value|l|:n|1|},
+                    pop{value|l|:n|-1|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [8, 7, 6, 5, 4]
+
+    def test_insert_at(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    insert_at{value|l.0|:n|10|},
+                    insert_at{value|l.3|:s|x|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [10, 9, 8, "x", 7, 6, 5, 4, 3]
+
+    def test_insert_at_out_of_range(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    insert_at{value|l.100|:n|10|}
+                ]
+            ];"""
+        )
+        assert resp == "Vector index does not exist"
+
+    def test_not_vector(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    push{value|num|:n|1|}
+                ]
+            ];"""
+        )
+        assert resp == "Array update operators support vectors only"
+
+    def test_set_last(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|l.$last|:s|x|}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """collection|test|:find[]
+            """
+        )
+        for k, v in resp[0]["data"].items():
+            assert v["l"] == [9, 8, 7, 6, 5, 4, "x"]
+
+    def test_set_last_empty(self, conn, objects):
+        conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|l|:v[]}
+                ]
+            ];"""
+        )
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|l.$last|:s|x|}
+                ]
+            ];"""
+        )
+        assert resp == "Vector is empty"
+
+    def test_set_missing_path_with_last(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                update[
+                    set{value|missing.$last|:s|x|}
+                ]
+            ];"""
+        )
+        assert resp[0]["meta"]["count"] == 0
+
+
+class TestUpsert:
+    def test_insert_then_update(self, conn):
+        query = """
+            collection|test|:q[
+                find[
+                    eq{value|name|:s|counter|}
+                ],
+                upsert[
+                    inc{value|hits|:n|1|}
+                ]
+            ];"""
+        resp = conn.send_query(query)
+        assert resp[0]["meta"]["count"] == 1
+        assert resp[0]["meta"]["upserted"] is True
+
+        resp = conn.send_query(query)
+        assert resp[0]["meta"]["count"] == 1
+        assert resp[0]["meta"]["upserted"] is False
+
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[]
+            ];"""
+        )
+        assert resp[0]["meta"]["count"] == 1
+        for v in resp[0]["data"].values():
+            assert v == {"name": "counter", "hits": 2}
+
+    def test_insert_with_set(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    and[
+                        eq{value|a|:n|1|},
+                        eq{value|b.c|:s|x|}
+                    ]
+                ],
+                upsert[
+                    set{value|d|:b|true|}
+                ]
+            ];"""
+        )
+        assert resp[0]["meta"]["upserted"] is True
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[]
+            ];"""
+        )
+        for v in resp[0]["data"].values():
+            assert v == {"a": 1, "b": {"c": "x"}, "d": True}
+
+    def test_conflict(self, conn):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[
+                    eq{value|a|:n|1|}
+                ],
+                upsert[
+                    set{value|a.b|:n|2|}
+                ]
+            ];"""
+        )
+        assert resp == "Upsert paths conflict"
+
+    def test_after_get(self, conn, objects):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                upsert[
+                    set{{value|num|:n|1|}}
+                ]
+            ];"""
+        )
+        assert resp == "Upsert query is unavailable"
@@ -0,0 +1,161 @@
+from annadb.dump import to_str
+
+
+class TestVersions:
+    def get_version(self, conn, link):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(link)}
+                ],
+                project{{
+                    s|v|:version
+                }}
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            return v["v"]
+
+    def test_insert(self, conn, objects):
+        assert self.get_version(conn, objects["data"][0]) == 1
+
+    def test_update(self, conn, objects):
+        conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                update[
+                    set{{value|name|:s|foo|}},
+                    inc{{value|num|:n|1|}}
+                ]
+            ]
+            """
+        )
+        assert self.get_version(conn, objects["data"][0]) == 2
+        assert self.get_version(conn, objects["data"][1]) == 1
+
+    def test_project(self, conn, objects):
+        resp = conn.send_query(
+            """
+            collection|test|:q[
+                find[],
+                project{
+                    s|v|:version,
+                    s|name|:keep
+                }
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v["v"] == 1
+
+
+class TestIfVersion:
+    def test_update(self, conn, objects):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                if_version(n|1|),
+                update[
+                    set{{value|name|:s|foo|}}
+                ]
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                if_version(n|1|),
+                update[
+                    set{{value|name|:s|bar|}}
+                ]
+            ]
+            """
+        )
+        assert (
+            resp
+            == f"Version mismatch: object {objects['data'][0].value} has version 2"
+        )
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:get[
+                {to_str(objects["data"][0])}
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v["name"] == "foo"
+
+    def test_delete(self, conn, objects):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                if_version(n|2|),
+                delete
+            ]
+            """
+        )
+        assert resp == f"Version mismatch: object {objects['data'][0].value} has version 1"
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                if_version(n|1|),
+                delete
+            ]
+            """
+        )
+        assert resp[0]["meta"]["count"] == 1
+
+    def test_aborts_transaction(self, conn, objects):
+        resp = conn.send_query(
+            f"""
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][1])}
+                ],
+                update[
+                    set{{value|name|:s|foo|}}
+                ]
+            ];
+            collection|test|:q[
+                get[
+                    {to_str(objects["data"][0])}
+                ],
+                if_version(n|5|),
+                update[
+                    set{{value|name|:s|bar|}}
+                ]
+            ];
+            """
+        )
+        assert resp == f"Version mismatch: object {objects['data'][0].value} has version 1"
+
+        resp = conn.send_query(
+            f"""
+            collection|test|:get[
+                {to_str(objects["data"][1])}
+            ]
+            """
+        )
+        for v in resp[0]["data"].values():
+            assert v["name"] == "test_1"